@@ -1,6 +1,6 @@
-CREATE TABLE users (
-    id SERIAL primary key,
-    name varchar(100) NOT NULL,
-    email varchar(100) UNIQUE NOT NULL,
-    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
-)
\ No newline at end of file
+-- スーパーユーザーはRLSを常にバイパスするため、アプリは専用ロールで接続する
+CREATE ROLE app_user LOGIN PASSWORD 'app_user' NOSUPERUSER NOBYPASSRLS;
+ALTER DATABASE app OWNER TO app_user;
+ALTER SCHEMA public OWNER TO app_user;
+
+-- テーブルはアプリに埋め込まれたマイグレーション (migrations/) で作成する
@@ -0,0 +1,16 @@
+CREATE TABLE users (
+    id SERIAL primary key,
+    tenant_id varchar(64) NOT NULL DEFAULT current_setting('app.tenant_id'),
+    name varchar(100) NOT NULL,
+    email varchar(100) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (tenant_id, email)
+);
+
+ALTER TABLE users ENABLE ROW LEVEL SECURITY;
+-- テーブル所有者にもポリシーを適用する
+ALTER TABLE users FORCE ROW LEVEL SECURITY;
+
+CREATE POLICY users_tenant_isolation ON users
+    USING (tenant_id = current_setting('app.tenant_id', true))
+    WITH CHECK (tenant_id = current_setting('app.tenant_id', true));
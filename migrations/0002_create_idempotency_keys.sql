@@ -0,0 +1,16 @@
+-- POST /users の再送を検出するための冪等キー
+CREATE TABLE idempotency_keys (
+    tenant_id varchar(64) NOT NULL DEFAULT current_setting('app.tenant_id'),
+    key varchar(255) NOT NULL,
+    request_hash char(64) NOT NULL,
+    response text NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (tenant_id, key)
+);
+
+ALTER TABLE idempotency_keys ENABLE ROW LEVEL SECURITY;
+ALTER TABLE idempotency_keys FORCE ROW LEVEL SECURITY;
+
+CREATE POLICY idempotency_keys_tenant_isolation ON idempotency_keys
+    USING (tenant_id = current_setting('app.tenant_id', true))
+    WITH CHECK (tenant_id = current_setting('app.tenant_id', true));
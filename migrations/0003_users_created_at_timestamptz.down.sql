@@ -0,0 +1,3 @@
+ALTER TABLE users
+    ALTER COLUMN created_at DROP NOT NULL,
+    ALTER COLUMN created_at TYPE timestamp USING created_at AT TIME ZONE 'UTC';
@@ -0,0 +1,11 @@
+-- 作成日時をタイムゾーン付きで保持し、必ず値を持つようにする
+-- 0001 では NULL を許していたため、先に埋めておく
+-- 全テナントの行を更新するため、このトランザクションの間だけ所有者へのRLSの適用を外す
+ALTER TABLE users NO FORCE ROW LEVEL SECURITY;
+UPDATE users SET created_at = now() WHERE created_at IS NULL;
+ALTER TABLE users FORCE ROW LEVEL SECURITY;
+
+ALTER TABLE users
+    ALTER COLUMN created_at TYPE timestamptz USING created_at AT TIME ZONE 'UTC',
+    ALTER COLUMN created_at SET DEFAULT CURRENT_TIMESTAMP,
+    ALTER COLUMN created_at SET NOT NULL;
@@ -0,0 +1,2 @@
+-- シーケンスの位置は戻さない (戻すと採番済みのIDと衝突する)
+SELECT 1;
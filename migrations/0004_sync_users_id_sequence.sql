@@ -0,0 +1,5 @@
+-- これまでクライアントが指定していたIDを採番済みとして扱い、シーケンスを既存の最大値の次に合わせる
+-- 全テナントの行を参照するため、このトランザクションの間だけ所有者へのRLSの適用を外す
+ALTER TABLE users NO FORCE ROW LEVEL SECURITY;
+SELECT setval(pg_get_serial_sequence('users', 'id'), COALESCE(MAX(id), 0) + 1, false) FROM users;
+ALTER TABLE users FORCE ROW LEVEL SECURITY;
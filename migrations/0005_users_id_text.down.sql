@@ -0,0 +1,3 @@
+-- 数値以外のIDが保存されている場合は失敗する
+ALTER TABLE users ALTER COLUMN id TYPE integer USING id::integer;
+ALTER TABLE users ALTER COLUMN id SET DEFAULT nextval('users_id_seq');
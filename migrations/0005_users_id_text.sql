@@ -0,0 +1,4 @@
+-- UUIDv7 や ULID も保持できるようIDを文字列にする。IDはアプリが保存前に生成するため既定値は外す
+-- シーケンスは sequence 方式の採番で引き続き使う
+ALTER TABLE users ALTER COLUMN id DROP DEFAULT;
+ALTER TABLE users ALTER COLUMN id TYPE varchar(36) USING id::text;
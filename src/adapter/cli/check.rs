@@ -0,0 +1,44 @@
+use sqlx::PgPool;
+
+use crate::adapter::cli::error::CliError;
+use crate::adapter::config::AppConfig;
+use crate::adapter::init::AppInitializer;
+use crate::adapter::store::pg::migration::Migrator;
+
+// 設定の読み込みは呼び出し前に済んでいるので、ここではデータベースへの到達性を確認する
+pub async fn run(config: &AppConfig) -> Result<(), CliError> {
+    println!("config: ok");
+
+    let pool = AppInitializer::connect(config).await?;
+    ping(&pool)
+        .await
+        .map_err(|e| CliError::CommandFailed(format!("database: {}", e)))?;
+    println!("database: ok");
+
+    for (index, url) in config.replica_urls().iter().enumerate() {
+        let replica = PgPool::connect(url)
+            .await
+            .map_err(|e| CliError::CommandFailed(format!("replica_{}: {}", index, e)))?;
+        ping(&replica)
+            .await
+            .map_err(|e| CliError::CommandFailed(format!("replica_{}: {}", index, e)))?;
+        replica.close().await;
+        println!("replica_{}: ok", index);
+    }
+
+    let pending = Migrator::new(pool.clone()).pending().await?;
+    pool.close().await;
+    if !pending.is_empty() {
+        return Err(CliError::CommandFailed(format!(
+            "migrations: {} pending",
+            pending.len()
+        )));
+    }
+    println!("migrations: ok");
+
+    Ok(())
+}
+
+async fn ping(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1").execute(pool).await.map(|_| ())
+}
@@ -0,0 +1,94 @@
+use clap::{Parser, Subcommand};
+
+use crate::adapter::config::ConfigArgs;
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(flatten)]
+    pub config: ConfigArgs,
+
+    // 省略した場合は serve として扱う
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start the HTTP server
+    Serve,
+
+    /// Manage schema migrations
+    Migrate {
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+
+    /// Manage users through the same use cases as the HTTP API
+    Users {
+        /// Tenant the command runs as
+        #[arg(long)]
+        tenant: String,
+
+        #[command(subcommand)]
+        command: UsersCommand,
+    },
+
+    /// Validate the configuration and check database connectivity
+    Check,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateCommand {
+    /// Apply pending migrations
+    Up {
+        /// List the migrations that would be applied without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Revert the most recently applied migrations
+    Down {
+        /// Number of migrations to revert
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+    },
+
+    /// Show which migrations have been applied
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UsersCommand {
+    /// Create a user
+    Create {
+        /// Id of the new user (requires --allow-client-ids)
+        #[arg(long)]
+        id: Option<String>,
+
+        #[arg(long)]
+        name: String,
+
+        #[arg(long)]
+        email: String,
+
+        /// Key used to detect retries of the same request
+        #[arg(long)]
+        idempotency_key: Option<String>,
+    },
+
+    /// Show a user
+    Get { id: String },
+
+    /// List users ordered by creation time
+    List {
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+    },
+
+    /// Delete a user
+    Delete { id: String },
+}
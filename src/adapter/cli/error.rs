@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::adapter::init::AppInitializerError;
+use crate::adapter::store::pg::migration::MigrationError;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error(transparent)]
+    InitError(#[from] AppInitializerError),
+
+    #[error(transparent)]
+    MigrationError(#[from] MigrationError),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("{0}")]
+    CommandFailed(String),
+}
@@ -0,0 +1,55 @@
+use crate::adapter::cli::command::MigrateCommand;
+use crate::adapter::cli::error::CliError;
+use crate::adapter::config::AppConfig;
+use crate::adapter::init::AppInitializer;
+use crate::adapter::store::pg::migration::{MigrationState, Migrator};
+
+pub async fn run(config: &AppConfig, command: MigrateCommand) -> Result<(), CliError> {
+    let pool = AppInitializer::connect(config).await?;
+    let migrator = Migrator::new(pool.clone());
+
+    match command {
+        MigrateCommand::Up { dry_run: true } => {
+            let pending = migrator.pending().await?;
+            if pending.is_empty() {
+                println!("No pending migrations");
+            }
+            for migration in pending {
+                println!("Would apply {:04} {}", migration.version, migration.name);
+            }
+        }
+        MigrateCommand::Up { dry_run: false } => {
+            let applied = migrator.run().await?;
+            if applied.is_empty() {
+                println!("No pending migrations");
+            }
+            for migration in applied {
+                println!("Applied {:04} {}", migration.version, migration.name);
+            }
+        }
+        MigrateCommand::Down { steps } => {
+            let reverted = migrator.revert(steps).await?;
+            if reverted.is_empty() {
+                println!("No applied migrations");
+            }
+            for migration in reverted {
+                println!("Reverted {:04} {}", migration.version, migration.name);
+            }
+        }
+        MigrateCommand::Status => {
+            for status in migrator.status().await? {
+                let state = match status.state {
+                    MigrationState::Applied => "applied",
+                    MigrationState::Pending => "pending",
+                };
+                println!(
+                    "{:04} {:<32} {}",
+                    status.migration.version, status.migration.name, state
+                );
+            }
+        }
+    }
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod check;
+pub mod command;
+pub mod error;
+pub mod migrate;
+pub mod presenter;
+pub mod users;
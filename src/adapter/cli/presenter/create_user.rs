@@ -0,0 +1,36 @@
+use crate::adapter::cli::error::CliError;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::create_user::{
+    CreateUserError, CreateUserOutputBoundary, CreateUserOutputError,
+};
+
+pub struct CreateUserPresenter {
+    output: Option<UserReadModel>,
+}
+
+impl CreateUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+
+    pub fn success(&self) -> Result<String, CliError> {
+        self.output
+            .as_ref()
+            .map(|user| format!("Created user {}", user.id))
+            .ok_or_else(|| CliError::CommandFailed("Output not set by presenter".to_string()))
+    }
+
+    pub fn failure(&self, error: CreateUserError) -> CliError {
+        match error {
+            CreateUserError::ValidationError(error) => CliError::InvalidArgument(error.to_string()),
+            error => CliError::CommandFailed(format!("Failed to create user: {}", error)),
+        }
+    }
+}
+
+impl CreateUserOutputBoundary for CreateUserPresenter {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), CreateUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::adapter::cli::error::CliError;
+use crate::core::port::delete_user::{
+    DeleteUserError, DeleteUserOutputBoundary, DeleteUserOutputError,
+};
+
+pub struct DeleteUserPresenter {
+    output: Option<String>,
+}
+
+impl DeleteUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+
+    pub fn success(&self) -> Result<String, CliError> {
+        self.output
+            .as_ref()
+            .map(|id| format!("Deleted user {}", id))
+            .ok_or_else(|| CliError::CommandFailed("Output not set by presenter".to_string()))
+    }
+
+    pub fn failure(&self, error: DeleteUserError) -> CliError {
+        CliError::CommandFailed(format!("Failed to delete user: {}", error))
+    }
+}
+
+impl DeleteUserOutputBoundary for DeleteUserPresenter {
+    fn execute(&mut self, output: String) -> Result<(), DeleteUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
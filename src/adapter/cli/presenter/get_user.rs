@@ -0,0 +1,39 @@
+use crate::adapter::cli::error::CliError;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::get_user::{GetUserError, GetUserOutputBoundary, GetUserOutputError};
+
+pub struct GetUserPresenter {
+    output: Option<UserReadModel>,
+}
+
+impl GetUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+
+    pub fn success(&self) -> Result<String, CliError> {
+        self.output
+            .as_ref()
+            .map(|user| {
+                format!(
+                    "id:         {}\nname:       {}\nemail:      {}\ncreated_at: {}",
+                    user.id,
+                    user.name,
+                    user.email,
+                    user.created_at.to_rfc3339()
+                )
+            })
+            .ok_or_else(|| CliError::CommandFailed("Output not set by presenter".to_string()))
+    }
+
+    pub fn failure(&self, error: GetUserError) -> CliError {
+        CliError::CommandFailed(format!("Failed to get user: {}", error))
+    }
+}
+
+impl GetUserOutputBoundary for GetUserPresenter {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), GetUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
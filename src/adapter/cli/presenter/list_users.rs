@@ -0,0 +1,76 @@
+use crate::adapter::cli::error::CliError;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::list_users::{
+    ListUsersError, ListUsersOutputBoundary, ListUsersOutputError,
+};
+
+pub struct ListUsersPresenter {
+    output: Option<Vec<UserReadModel>>,
+}
+
+impl ListUsersPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+
+    // 列幅を揃えた表形式で出力する
+    pub fn success(&self) -> Result<String, CliError> {
+        let users = self
+            .output
+            .as_ref()
+            .ok_or_else(|| CliError::CommandFailed("Output not set by presenter".to_string()))?;
+        let rows: Vec<[String; 4]> = users
+            .iter()
+            .map(|user| {
+                [
+                    user.id.to_string(),
+                    user.name.clone(),
+                    user.email.clone(),
+                    user.created_at.to_rfc3339(),
+                ]
+            })
+            .collect();
+        let header = [
+            "ID".to_string(),
+            "NAME".to_string(),
+            "EMAIL".to_string(),
+            "CREATED_AT".to_string(),
+        ];
+        let widths: Vec<usize> = (0..4)
+            .map(|column| {
+                std::iter::once(&header)
+                    .chain(&rows)
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let lines: Vec<String> = std::iter::once(&header)
+            .chain(&rows)
+            .map(|row| {
+                format!(
+                    "{:<id$}  {:<name$}  {:<email$}  {}",
+                    row[0],
+                    row[1],
+                    row[2],
+                    row[3],
+                    id = widths[0],
+                    name = widths[1],
+                    email = widths[2]
+                )
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+
+    pub fn failure(&self, error: ListUsersError) -> CliError {
+        CliError::CommandFailed(format!("Failed to list users: {}", error))
+    }
+}
+
+impl ListUsersOutputBoundary for ListUsersPresenter {
+    fn execute(&mut self, output: Vec<UserReadModel>) -> Result<(), ListUsersOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
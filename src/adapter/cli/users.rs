@@ -0,0 +1,83 @@
+use crate::adapter::cli::command::UsersCommand;
+use crate::adapter::cli::error::CliError;
+use crate::adapter::cli::presenter::create_user::CreateUserPresenter;
+use crate::adapter::cli::presenter::delete_user::DeleteUserPresenter;
+use crate::adapter::cli::presenter::get_user::GetUserPresenter;
+use crate::adapter::cli::presenter::list_users::ListUsersPresenter;
+use crate::adapter::config::AppConfig;
+use crate::adapter::init::AppInitializer;
+use crate::core::domain::entity::user::user::UnvalidatedCreateUserInput;
+use crate::core::domain::idempotency::IdempotencyKey;
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::list_users::ListUsersInput;
+
+// HTTP APIと同じユースケースを呼び出し、結果を端末向けに表示する
+pub async fn run(
+    config: &AppConfig,
+    tenant: String,
+    command: UsersCommand,
+) -> Result<(), CliError> {
+    let tenant_id =
+        TenantId::try_from(tenant).map_err(|e| CliError::InvalidArgument(e.to_string()))?;
+    let use_cases = AppInitializer::initialize_use_cases(config).await?;
+
+    let output = match command {
+        UsersCommand::Create {
+            id,
+            name,
+            email,
+            idempotency_key,
+        } => {
+            let idempotency_key = idempotency_key
+                .map(IdempotencyKey::try_from)
+                .transpose()
+                .map_err(|e| CliError::InvalidArgument(e.to_string()))?;
+            let input = UnvalidatedCreateUserInput { id, name, email };
+            let mut presenter = CreateUserPresenter::new();
+            match use_cases
+                .create_user
+                .execute(tenant_id, input, idempotency_key, &mut presenter)
+                .await
+            {
+                Ok(_) => presenter.success()?,
+                Err(error) => return Err(presenter.failure(error)),
+            }
+        }
+        UsersCommand::Get { id } => {
+            let mut presenter = GetUserPresenter::new();
+            match use_cases
+                .get_user
+                .execute(tenant_id, id, &mut presenter)
+                .await
+            {
+                Ok(_) => presenter.success()?,
+                Err(error) => return Err(presenter.failure(error)),
+            }
+        }
+        UsersCommand::List { limit, offset } => {
+            let mut presenter = ListUsersPresenter::new();
+            match use_cases
+                .list_users
+                .execute(tenant_id, ListUsersInput { limit, offset }, &mut presenter)
+                .await
+            {
+                Ok(_) => presenter.success()?,
+                Err(error) => return Err(presenter.failure(error)),
+            }
+        }
+        UsersCommand::Delete { id } => {
+            let mut presenter = DeleteUserPresenter::new();
+            match use_cases
+                .delete_user
+                .execute(tenant_id, id, &mut presenter)
+                .await
+            {
+                Ok(_) => presenter.success()?,
+                Err(error) => return Err(presenter.failure(error)),
+            }
+        }
+    };
+
+    println!("{}", output);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::adapter::store::pg::migration::Migrator;
+
+const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    // トラフィックは受けられるが一部の機能が劣化している
+    Degraded,
+    Down,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComponentHealth {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl ComponentHealth {
+    fn ok() -> Self {
+        Self {
+            status: HealthStatus::Ok,
+            message: None,
+            details: None,
+        }
+    }
+
+    fn failed(status: HealthStatus, message: impl ToString) -> Self {
+        Self {
+            status,
+            message: Some(message.to_string()),
+            details: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+impl HealthReport {
+    pub fn up() -> Self {
+        Self {
+            status: HealthStatus::Ok,
+            components: BTreeMap::new(),
+        }
+    }
+
+    // 最も悪いコンポーネントの状態を全体の状態とする
+    fn from_components(components: BTreeMap<String, ComponentHealth>) -> Self {
+        let status = components
+            .values()
+            .map(|component| component.status)
+            .max()
+            .unwrap_or(HealthStatus::Ok);
+        Self { status, components }
+    }
+}
+
+pub struct ReadinessCheck {
+    pool: PgPool,
+    replicas: Vec<PgPool>,
+    timeout: Duration,
+}
+
+impl ReadinessCheck {
+    pub fn new(pool: PgPool, replicas: Vec<PgPool>) -> Self {
+        Self {
+            pool,
+            replicas,
+            timeout: DEFAULT_CHECK_TIMEOUT,
+        }
+    }
+
+    pub async fn run(&self) -> HealthReport {
+        let mut components = BTreeMap::new();
+        // 確認用のクエリが使う接続を含めないよう、先にプールの状態を取る
+        let pool = pool_health(&self.pool);
+        // 全ての接続が使用中なら確認用のクエリは接続待ちでタイムアウトするだけなので実行しない
+        // 忙しいだけのインスタンスを Down としてトラフィックから外さないよう Degraded とする
+        if pool.status == HealthStatus::Degraded {
+            for component in ["database", "migrations"] {
+                components.insert(
+                    component.to_string(),
+                    ComponentHealth::failed(HealthStatus::Degraded, "skipped: pool saturated"),
+                );
+            }
+        } else {
+            components.insert(
+                "database".to_string(),
+                self.check(HealthStatus::Down, ping(&self.pool)).await,
+            );
+            components.insert("migrations".to_string(), self.check_migrations().await);
+        }
+        components.insert("pool".to_string(), pool);
+        // レプリカが落ちていても読み取りはプライマリへフォールバックする
+        for (index, replica) in self.replicas.iter().enumerate() {
+            components.insert(
+                format!("replica_{}", index),
+                self.check(HealthStatus::Degraded, ping(replica)).await,
+            );
+        }
+        HealthReport::from_components(components)
+    }
+
+    async fn check_migrations(&self) -> ComponentHealth {
+        let migrator = Migrator::new(self.pool.clone());
+        let pending = async {
+            if !migrator.is_initialised().await? {
+                return Ok(None);
+            }
+            migrator.pending().await.map(Some)
+        };
+        match tokio::time::timeout(self.timeout, pending).await {
+            Ok(Ok(None)) => ComponentHealth::failed(HealthStatus::Down, "not initialised"),
+            Ok(Ok(Some(pending))) if pending.is_empty() => ComponentHealth::ok(),
+            Ok(Ok(Some(pending))) => ComponentHealth::failed(
+                HealthStatus::Down,
+                format!("{} pending migrations", pending.len()),
+            ),
+            Ok(Err(e)) => ComponentHealth::failed(HealthStatus::Down, e),
+            Err(_) => ComponentHealth::failed(HealthStatus::Down, "timed out"),
+        }
+    }
+
+    async fn check(
+        &self,
+        failure: HealthStatus,
+        probe: impl Future<Output = Result<(), sqlx::Error>>,
+    ) -> ComponentHealth {
+        match tokio::time::timeout(self.timeout, probe).await {
+            Ok(Ok(())) => ComponentHealth::ok(),
+            Ok(Err(e)) => ComponentHealth::failed(failure, e),
+            Err(_) => ComponentHealth::failed(failure, "timed out"),
+        }
+    }
+}
+
+async fn ping(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1").execute(pool).await.map(|_| ())
+}
+
+// 全ての接続が使用中の場合は新しいリクエストが接続待ちになる
+fn pool_health(pool: &PgPool) -> ComponentHealth {
+    let size = pool.size();
+    let idle = pool.num_idle() as u32;
+    let max = pool.options().get_max_connections();
+    let in_use = size.saturating_sub(idle);
+    let saturation = in_use as f64 / max as f64;
+    let status = if in_use >= max {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Ok
+    };
+    ComponentHealth {
+        status,
+        message: None,
+        details: Some(serde_json::json!({
+            "size": size,
+            "idle": idle,
+            "in_use": in_use,
+            "max": max,
+            "saturation": saturation,
+        })),
+    }
+}
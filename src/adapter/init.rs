@@ -1,37 +1,318 @@
 use crate::adapter::config::AppConfig;
+use crate::adapter::health::ReadinessCheck;
+use crate::adapter::id_generator::{
+    IdStrategy, SnowflakeIdGenerator, UlidIdGenerator, UuidV7IdGenerator,
+};
+use crate::adapter::metrics::MetricsExporter;
+use crate::adapter::shutdown::Shutdown;
+use crate::adapter::store::pg::command::idempotency::PgIdempotencyRepository;
 use crate::adapter::store::pg::command::user::PgUserRepository;
+use crate::adapter::store::pg::id_generator::{PgSequenceIdGenerator, USERS_ID_SEQUENCE};
+use crate::adapter::store::pg::listener::PgNotificationListener;
+use crate::adapter::store::pg::migration::Migrator;
+use crate::adapter::store::pg::query::user::PgUserQuery;
+use crate::adapter::store::pg::tenant_schema::{
+    create_tenant_schema, outdated_columns, schema_name, TenancyMode,
+};
 use crate::adapter::store::pg::transaction_manager::PgTransactionManager;
 use crate::adapter::web::app_state::AppState;
+use crate::core::domain::id_generator::IdGenerator;
+use crate::core::domain::notification::USER_CREATED_CHANNEL;
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::create_user::CreateUserInputBoundary;
+use crate::core::port::delete_user::DeleteUserInputBoundary;
+use crate::core::port::get_user::GetUserInputBoundary;
+use crate::core::port::list_users::ListUsersInputBoundary;
+use crate::core::port::update_user::UpdateUserInputBoundary;
 use crate::core::use_case::create_user::CreateUserUseCase;
+use crate::core::use_case::delete_user::DeleteUserUseCase;
+use crate::core::use_case::get_user::GetUserUseCase;
+use crate::core::use_case::list_users::ListUsersUseCase;
+use crate::core::use_case::update_user::UpdateUserUseCase;
+use futures::StreamExt;
+use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+const MAX_CONNECT_BACKOFF: Duration = Duration::from_secs(10);
 
 pub struct AppInitializer;
 
+pub struct UserUseCases {
+    pub create_user: Arc<dyn CreateUserInputBoundary>,
+    pub get_user: Arc<dyn GetUserInputBoundary>,
+    pub list_users: Arc<dyn ListUsersInputBoundary>,
+    pub update_user: Arc<dyn UpdateUserInputBoundary>,
+    pub delete_user: Arc<dyn DeleteUserInputBoundary>,
+}
+
 impl AppInitializer {
-    pub async fn initialize(config: AppConfig) -> Result<Arc<AppState>, AppInitializerError> {
-        let pool = PgPool::connect(&config.db_url())
+    pub async fn initialize(
+        config: AppConfig,
+        shutdown: &Shutdown,
+    ) -> Result<Arc<AppState>, AppInitializerError> {
+        let pool = Self::connect(&config).await?;
+        Self::migrate(&config, &pool).await?;
+
+        if config.tenancy_mode() == TenancyMode::Schema {
+            for tenant in config.tenant_schemas() {
+                let tenant_id = TenantId::try_from(tenant.clone())
+                    .map_err(|e| AppInitializerError::DatabaseInitError(e.to_string()))?;
+                create_tenant_schema(&pool, &tenant_id)
+                    .await
+                    .map_err(|e| AppInitializerError::DatabaseInitError(e.to_string()))?;
+                // 古い定義のままのテーブルでは書き込みも読み取りも失敗するため起動しない
+                let outdated = outdated_columns(&pool, &tenant_id)
+                    .await
+                    .map_err(|e| AppInitializerError::DatabaseInitError(e.to_string()))?;
+                if !outdated.is_empty() {
+                    return Err(AppInitializerError::MigrationError(format!(
+                        "Tenant schema {} is behind public (columns: {}); migrate or recreate it",
+                        schema_name(tenant_id.as_str()),
+                        outdated.join(", ")
+                    )));
+                }
+            }
+        }
+
+        let listener = PgNotificationListener::connect(&pool, &[USER_CREATED_CHANNEL])
             .await
             .map_err(|e| AppInitializerError::DatabaseInitError(e.to_string()))?;
+        // リスナーはプールの接続を1つ保持し続けるため、プールを閉じる前に止める
+        let draining = shutdown.draining();
+        tokio::spawn(async move {
+            let mut notifications = Box::pin(
+                listener
+                    .into_stream()
+                    .take_until(draining.cancelled_owned()),
+            );
+            while let Some(notification) = notifications.next().await {
+                match notification {
+                    Ok(notification) => info!(
+                        channel = %notification.channel,
+                        payload = %notification.payload,
+                        "Received notification"
+                    ),
+                    Err(e) => warn!(error = %e, "Notification listener error"),
+                }
+            }
+        });
+
+        let replicas = Self::replica_pools(&config)?;
+
+        let metrics = replicas.iter().enumerate().fold(
+            MetricsExporter::install()
+                .map_err(|e| AppInitializerError::MetricsInitError(e.to_string()))?
+                .with_pool("primary", pool.clone()),
+            |metrics, (index, replica)| {
+                metrics.with_pool(format!("replica_{}", index), replica.clone())
+            },
+        );
 
-        let transaction_manager = Arc::new(PgTransactionManager::new(pool));
-        let create_user_repository = Arc::new(PgUserRepository);
-        let user_create_use_case = Arc::new(CreateUserUseCase::new(
-            create_user_repository.clone(),
-            transaction_manager.clone(),
-        ));
+        let use_cases = Self::user_use_cases(
+            &config,
+            config.id_node_id(),
+            pool.clone(),
+            replicas.clone(),
+            shutdown.aborting(),
+        );
 
         Ok(Arc::new(AppState {
-            transaction_manager,
-            create_user_repository,
-            user_create_use_case,
+            user_create_use_case: use_cases.create_user,
+            user_get_use_case: use_cases.get_user,
+            user_list_use_case: use_cases.list_users,
+            user_update_use_case: use_cases.update_user,
+            user_delete_use_case: use_cases.delete_user,
+            metrics: Arc::new(metrics),
+            readiness: Arc::new(ReadinessCheck::new(pool.clone(), replicas.clone())),
+            admin_token: config.admin_token().map(str::to_string),
+            pool,
+            replicas,
         }))
     }
+
+    // CLIから使う場合は通知の購読やメトリクスを省き、ユースケースだけを組み立てる
+    pub async fn initialize_use_cases(
+        config: &AppConfig,
+    ) -> Result<UserUseCases, AppInitializerError> {
+        let pool = Self::connect(config).await?;
+        Self::migrate(config, &pool).await?;
+        let replicas = Self::replica_pools(config)?;
+        // サーバーと重複するIDを作らないよう、CLI用のノードIDで採番する
+        Ok(Self::user_use_cases(
+            config,
+            config.id_cli_node_id(),
+            pool,
+            replicas,
+            CancellationToken::new(),
+        ))
+    }
+
+    fn user_use_cases(
+        config: &AppConfig,
+        node_id: u16,
+        pool: PgPool,
+        replicas: Vec<PgPool>,
+        cancellation: CancellationToken,
+    ) -> UserUseCases {
+        let transaction_manager = Arc::new(
+            PgTransactionManager::new(pool)
+                .with_replicas(replicas, config.replica_selection())
+                .with_tenancy(config.tenancy_mode())
+                .with_statement_journal(config.redaction().clone(), config.log_statements())
+                .with_max_retries(config.transaction_retries())
+                .with_cancellation(cancellation),
+        );
+        let user_repository = Arc::new(PgUserRepository);
+        let idempotency_repository = Arc::new(PgIdempotencyRepository);
+        let user_query = Arc::new(PgUserQuery);
+
+        UserUseCases {
+            create_user: Arc::new(
+                CreateUserUseCase::new(
+                    user_repository.clone(),
+                    Self::id_generator(config, node_id),
+                    user_query.clone(),
+                    idempotency_repository,
+                    transaction_manager.clone(),
+                )
+                .with_client_ids(config.allow_client_ids()),
+            ),
+            get_user: Arc::new(GetUserUseCase::new(
+                user_query.clone(),
+                transaction_manager.clone(),
+            )),
+            list_users: Arc::new(ListUsersUseCase::new(
+                user_query.clone(),
+                transaction_manager.clone(),
+            )),
+            update_user: Arc::new(UpdateUserUseCase::new(
+                user_repository.clone(),
+                user_query,
+                transaction_manager.clone(),
+            )),
+            delete_user: Arc::new(DeleteUserUseCase::new(user_repository, transaction_manager)),
+        }
+    }
+
+    fn id_generator(config: &AppConfig, node_id: u16) -> Arc<dyn IdGenerator> {
+        match config.id_strategy() {
+            IdStrategy::Sequence => Arc::new(PgSequenceIdGenerator::new(USERS_ID_SEQUENCE)),
+            IdStrategy::UuidV7 => Arc::new(UuidV7IdGenerator),
+            IdStrategy::Ulid => Arc::new(UlidIdGenerator),
+            IdStrategy::Snowflake => Arc::new(SnowflakeIdGenerator::new(node_id)),
+        }
+    }
+
+    // レプリカが起動していなくてもアプリは起動できるよう遅延接続にする
+    // 停止したレプリカで待たされないよう、接続の待ち時間はプライマリより短くする
+    fn replica_pools(config: &AppConfig) -> Result<Vec<PgPool>, AppInitializerError> {
+        config
+            .replica_urls()
+            .iter()
+            .map(|url| {
+                Self::pool_options(config)
+                    .acquire_timeout(config.replica_acquire_timeout())
+                    .connect_lazy(url)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppInitializerError::DatabaseInitError(e.to_string()))
+    }
+
+    fn pool_options(config: &AppConfig) -> PgPoolOptions {
+        PgPoolOptions::new()
+            .max_connections(config.max_connections())
+            .min_connections(config.min_connections())
+            .acquire_timeout(config.acquire_timeout())
+            .idle_timeout(config.idle_timeout())
+            .max_lifetime(config.max_lifetime())
+            .test_before_acquire(config.test_before_acquire())
+    }
+
+    async fn migrate(config: &AppConfig, pool: &PgPool) -> Result<(), AppInitializerError> {
+        let migrator = Migrator::new(pool.clone());
+        if config.migrate_on_startup() {
+            let applied = migrator
+                .run()
+                .await
+                .map_err(|e| AppInitializerError::MigrationError(e.to_string()))?;
+            for migration in applied {
+                info!(
+                    version = migration.version,
+                    name = migration.name,
+                    "Applied migration"
+                );
+            }
+        } else {
+            // 適用済みのマイグレーションが編集されていれば起動しない
+            let pending = migrator
+                .pending()
+                .await
+                .map_err(|e| AppInitializerError::MigrationError(e.to_string()))?;
+            for migration in pending {
+                warn!(
+                    version = migration.version,
+                    name = migration.name,
+                    "Pending migration"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // docker-compose などでPostgresより先に起動した場合に備え、一定回数まで再試行する
+    pub async fn connect(config: &AppConfig) -> Result<PgPool, AppInitializerError> {
+        let mut backoff = config.connect_backoff().min(MAX_CONNECT_BACKOFF);
+        let mut attempt = 0;
+        loop {
+            match Self::pool_options(config).connect(config.db_url()).await {
+                Ok(pool) => return Ok(pool),
+                Err(e) if attempt < config.connect_retries() && is_transient(&e) => {
+                    attempt += 1;
+                    warn!(
+                        error = %e,
+                        attempt,
+                        max_retries = config.connect_retries(),
+                        backoff_ms = backoff.as_millis() as u64,
+                        "Failed to connect to database, retrying"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_CONNECT_BACKOFF);
+                }
+                Err(e) => {
+                    return Err(AppInitializerError::DatabaseInitError(format!(
+                        "{} (after {} retries)",
+                        e, attempt
+                    )))
+                }
+            }
+        }
+    }
+}
+
+// パスワードの誤りや存在しないデータベースは待っても直らないため、すぐに失敗させる
+fn is_transient(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => true,
+        // 57P03: cannot_connect_now (Postgresの起動中やリカバリ中)
+        sqlx::Error::Database(error) => error.code().as_deref() == Some("57P03"),
+        _ => false,
+    }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error)]
 pub enum AppInitializerError {
     #[error("Failed to initialize database: {0}")]
     DatabaseInitError(String),
+
+    #[error("Failed to migrate database: {0}")]
+    MigrationError(String),
+
+    #[error("Failed to initialize metrics: {0}")]
+    MetricsInitError(String),
 }
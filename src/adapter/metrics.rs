@@ -0,0 +1,48 @@
+use metrics_exporter_prometheus::{BuildError, Matcher, PrometheusBuilder, PrometheusHandle};
+use sqlx::PgPool;
+
+pub const TRANSACTIONS_COMMITTED_TOTAL: &str = "transactions_committed_total";
+pub const TRANSACTIONS_ROLLED_BACK_TOTAL: &str = "transactions_rolled_back_total";
+pub const TRANSACTION_RETRIES_TOTAL: &str = "transaction_retries_total";
+pub const TRANSACTION_DURATION_SECONDS: &str = "transaction_duration_seconds";
+pub const DB_POOL_CONNECTIONS: &str = "db_pool_connections";
+pub const DB_POOL_IDLE_CONNECTIONS: &str = "db_pool_idle_connections";
+pub const DB_POOL_ACQUIRE_SECONDS: &str = "db_pool_acquire_seconds";
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+pub struct MetricsExporter {
+    handle: PrometheusHandle,
+    pools: Vec<(String, PgPool)>,
+}
+
+impl MetricsExporter {
+    pub fn install() -> Result<Self, BuildError> {
+        let handle = PrometheusBuilder::new()
+            .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), LATENCY_BUCKETS)?
+            .install_recorder()?;
+        Ok(Self {
+            handle,
+            pools: Vec::new(),
+        })
+    }
+
+    pub fn with_pool(mut self, name: impl Into<String>, pool: PgPool) -> Self {
+        self.pools.push((name.into(), pool));
+        self
+    }
+
+    // プールの状態はスクレイプ時点の値をゲージに反映する
+    pub fn render(&self) -> String {
+        for (name, pool) in &self.pools {
+            metrics::gauge!(DB_POOL_CONNECTIONS, "pool" => name.clone()).set(pool.size() as f64);
+            metrics::gauge!(DB_POOL_IDLE_CONNECTIONS, "pool" => name.clone())
+                .set(pool.num_idle() as f64);
+        }
+        self.handle.render()
+    }
+}
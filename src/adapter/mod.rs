@@ -1,4 +1,10 @@
+pub mod cli;
 pub mod config;
+pub mod health;
+pub mod id_generator;
 pub mod init;
+pub mod metrics;
+pub mod shutdown;
 pub mod store;
+pub mod telemetry;
 pub mod web;
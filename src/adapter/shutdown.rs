@@ -0,0 +1,50 @@
+use tokio_util::sync::CancellationToken;
+
+// シャットダウンの2段階を表す
+// draining: 新しい接続の受付を止め、実行中のリクエストの完了を待つ
+// aborting: 締め切りを過ぎたので、実行中の作業単位をロールバックさせる
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    draining: CancellationToken,
+    aborting: CancellationToken,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn draining(&self) -> CancellationToken {
+        self.draining.clone()
+    }
+
+    pub fn aborting(&self) -> CancellationToken {
+        self.aborting.clone()
+    }
+
+    pub fn start_draining(&self) {
+        self.draining.cancel();
+    }
+
+    pub fn abort(&self) {
+        self.draining.cancel();
+        self.aborting.cancel();
+    }
+}
+
+// SIGTERM (コンテナの停止) または Ctrl-C を待つ
+pub async fn wait_for_signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut terminate =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result,
+            _ = terminate.recv() => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
+}
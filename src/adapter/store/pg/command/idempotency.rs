@@ -0,0 +1,79 @@
+use crate::core::domain::command::CommandError;
+use crate::core::domain::idempotency::{IdempotencyKey, IdempotencyRecord, IdempotencyRepository};
+use crate::core::domain::transaction::{ToSql, TransactionWrapper};
+use async_trait::async_trait;
+use futures::StreamExt;
+
+pub struct PgIdempotencyRepository;
+#[async_trait]
+impl IdempotencyRepository for PgIdempotencyRepository {
+    async fn lock(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        key: &IdempotencyKey,
+    ) -> Result<(), CommandError> {
+        let query = "SELECT pg_advisory_xact_lock(hashtext(current_setting('app.tenant_id', true) || ':' || $1))";
+        let params: Vec<Box<dyn ToSql>> =
+            vec![Box::new(key.as_str().to_string()) as Box<dyn ToSql>];
+        transaction
+            .execute(query, params)
+            .await
+            .map_err(|e| CommandError::DatabaseError(e.to_string()))
+    }
+
+    async fn find(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        key: &IdempotencyKey,
+    ) -> Result<Option<IdempotencyRecord>, CommandError> {
+        let query = "SELECT request_hash, response FROM idempotency_keys WHERE key = $1";
+        let params: Vec<Box<dyn ToSql>> =
+            vec![Box::new(key.as_str().to_string()) as Box<dyn ToSql>];
+        let mut rows = transaction.fetch(query, params);
+        match rows.next().await {
+            Some(Ok(row)) => {
+                let (Some(request_hash), Some(response)) =
+                    (row.get_string("request_hash"), row.get_string("response"))
+                else {
+                    return Err(CommandError::DatabaseError(
+                        "Malformed idempotency record".to_string(),
+                    ));
+                };
+                Ok(Some(IdempotencyRecord {
+                    key: key.clone(),
+                    request_hash,
+                    response,
+                }))
+            }
+            Some(Err(e)) => Err(CommandError::DatabaseError(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    async fn insert(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        record: IdempotencyRecord,
+    ) -> Result<(), CommandError> {
+        let query =
+            "INSERT INTO idempotency_keys (key, request_hash, response) VALUES ($1, $2, $3)";
+        let params: Vec<Box<dyn ToSql>> = vec![
+            Box::new(record.key.as_str().to_string()) as Box<dyn ToSql>,
+            Box::new(record.request_hash) as Box<dyn ToSql>,
+            Box::new(record.response) as Box<dyn ToSql>,
+        ];
+        match transaction.execute(query, params).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                // 同じキーのリクエストが並行して処理された場合
+                if e.to_string().contains("unique constraint") {
+                    Err(CommandError::ConcurrencyError {
+                        entity_type: "IdempotencyKey".to_string(),
+                    })
+                } else {
+                    Err(CommandError::DatabaseError(e.to_string()))
+                }
+            }
+        }
+    }
+}
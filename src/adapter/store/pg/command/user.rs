@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use crate::core::domain::command::CommandError;
 use crate::core::domain::entity::user::{User, UserCommand};
-use crate::core::domain::transaction::{ToSql, TransactionWrapper};
+use crate::core::domain::transaction::{Row, ToSql, TransactionWrapper};
+use futures::StreamExt;
 
 pub struct PgUserRepository;
 #[async_trait]
@@ -13,16 +14,21 @@ impl UserCommand for PgUserRepository {
     ) -> Result<(), CommandError> {
         let query = "INSERT INTO users (id, name, email) VALUES ($1, $2, $3)";
         let params: Vec<Box<dyn ToSql>> = vec![
-            Box::new(user.id) as Box<dyn ToSql>,
-            Box::new(user.name) as Box<dyn ToSql>,
-            Box::new(user.email) as Box<dyn ToSql>,
+            Box::new(user.id.to_string()) as Box<dyn ToSql>,
+            Box::new(user.name.to_string()) as Box<dyn ToSql>,
+            Box::new(user.email.to_string()) as Box<dyn ToSql>,
         ];
         match transaction.execute(query, params).await {
             Ok(_) => Ok(()),
             Err(e) => {
                 // SQLxのエラーを適切なドメインエラーに変換
-                if e.to_string().contains("unique constraint") {
+                if e.to_string().contains("users_pkey") {
                     Err(CommandError::user_already_exists(user.id))
+                } else if e.to_string().contains("unique constraint") {
+                    Err(CommandError::AlreadyExists {
+                        entity_type: "User".to_string(),
+                        details: "email is already in use".to_string(),
+                    })
                 } else if e.to_string().contains("deadlock") {
                     Err(CommandError::ConcurrencyError {entity_type: "User".to_string()})
                 } else {
@@ -31,4 +37,86 @@ impl UserCommand for PgUserRepository {
             }
         }
     }
+
+    async fn find_by_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<Option<User>, CommandError> {
+        let query = "SELECT id, name, email FROM users WHERE id = $1";
+        let params: Vec<Box<dyn ToSql>> = vec![Box::new(id.to_string()) as Box<dyn ToSql>];
+        let mut rows = transaction.fetch(query, params);
+        match rows.next().await {
+            Some(Ok(row)) => user_from_row(&row).map(Some),
+            Some(Err(e)) => Err(CommandError::DatabaseError(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    async fn update(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        user: User,
+    ) -> Result<(), CommandError> {
+        let query = "UPDATE users SET name = $2, email = $3 WHERE id = $1 RETURNING id";
+        let params: Vec<Box<dyn ToSql>> = vec![
+            Box::new(user.id.to_string()) as Box<dyn ToSql>,
+            Box::new(String::from(user.name)) as Box<dyn ToSql>,
+            Box::new(user.email.to_string()) as Box<dyn ToSql>,
+        ];
+        let mut rows = transaction.fetch(query, params);
+        match rows.next().await {
+            Some(Ok(_)) => Ok(()),
+            Some(Err(e)) => {
+                // 同じテナントに同じメールアドレスのユーザーが既に存在する
+                if e.to_string().contains("unique constraint") {
+                    Err(CommandError::AlreadyExists {
+                        entity_type: "User".to_string(),
+                        details: "email is already in use".to_string(),
+                    })
+                } else if e.to_string().contains("deadlock") {
+                    Err(CommandError::ConcurrencyError {
+                        entity_type: "User".to_string(),
+                    })
+                } else {
+                    Err(CommandError::DatabaseError(e.to_string()))
+                }
+            }
+            None => Err(CommandError::user_not_found(user.id.as_str())),
+        }
+    }
+
+    async fn delete(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<(), CommandError> {
+        // 削除件数を知るため RETURNING で削除した行を受け取る
+        let query = "DELETE FROM users WHERE id = $1 RETURNING id";
+        let params: Vec<Box<dyn ToSql>> = vec![Box::new(id.to_string()) as Box<dyn ToSql>];
+        let mut rows = transaction.fetch(query, params);
+        match rows.next().await {
+            Some(Ok(_)) => Ok(()),
+            Some(Err(e)) if e.to_string().contains("deadlock") => {
+                Err(CommandError::ConcurrencyError {
+                    entity_type: "User".to_string(),
+                })
+            }
+            Some(Err(e)) => Err(CommandError::DatabaseError(e.to_string())),
+            None => Err(CommandError::user_not_found(id)),
+        }
+    }
+}
+
+fn user_from_row(row: &Row) -> Result<User, CommandError> {
+    let (Some(id), Some(name), Some(email)) = (
+        row.get_string("id"),
+        row.get_string("name"),
+        row.get_string("email"),
+    ) else {
+        return Err(CommandError::DatabaseError(
+            "Malformed user record".to_string(),
+        ));
+    };
+    User::rehydrate(id, name, email).map_err(|e| CommandError::DatabaseError(e.to_string()))
 }
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use crate::core::domain::id_generator::{IdGenerator, IdGeneratorError};
+use crate::core::domain::transaction::{ToSql, TransactionWrapper};
+
+// スキーマ分離モードでも全テナントで同じシーケンスを使うため、スキーマまで指定する
+pub const USERS_ID_SEQUENCE: &str = "public.users_id_seq";
+
+pub struct PgSequenceIdGenerator {
+    sequence: &'static str,
+}
+
+impl PgSequenceIdGenerator {
+    pub fn new(sequence: &'static str) -> Self {
+        Self { sequence }
+    }
+}
+
+#[async_trait]
+impl IdGenerator for PgSequenceIdGenerator {
+    async fn next_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<String, IdGeneratorError> {
+        let query = "SELECT nextval($1::regclass)::text AS id";
+        let params: Vec<Box<dyn ToSql>> =
+            vec![Box::new(self.sequence.to_string()) as Box<dyn ToSql>];
+        match transaction.fetch(query, params).next().await {
+            Some(Ok(row)) => row.get_string("id").ok_or_else(|| {
+                IdGeneratorError::DatabaseError("nextval returned no value".to_string())
+            }),
+            Some(Err(e)) => Err(IdGeneratorError::DatabaseError(e.to_string())),
+            None => Err(IdGeneratorError::DatabaseError(
+                "nextval returned no rows".to_string(),
+            )),
+        }
+    }
+
+    // 数値のIDだけがシーケンスと衝突し得る。シーケンスを戻さないよう、指定されたIDの方が大きい場合のみ進める
+    async fn reserve(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<(), IdGeneratorError> {
+        if id.parse::<i64>().map_or(true, |id| id <= 0) {
+            return Ok(());
+        }
+        let query = "SELECT setval($1::regclass, $2::bigint) \
+            FROM pg_sequence_last_value($1::regclass) AS last_value \
+            WHERE last_value IS NULL OR last_value < $2::bigint";
+        let params: Vec<Box<dyn ToSql>> = vec![
+            Box::new(self.sequence.to_string()) as Box<dyn ToSql>,
+            Box::new(id.to_string()) as Box<dyn ToSql>,
+        ];
+        transaction
+            .execute(query, params)
+            .await
+            .map(|_| ())
+            .map_err(|e| IdGeneratorError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,27 @@
+use futures::{Stream, StreamExt};
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+
+use crate::core::domain::notification::Notification;
+
+pub struct PgNotificationListener {
+    listener: PgListener,
+}
+
+impl PgNotificationListener {
+    pub async fn connect(pool: &PgPool, channels: &[&str]) -> Result<Self, sqlx::Error> {
+        let mut listener = PgListener::connect_with(pool).await?;
+        listener.listen_all(channels.iter().copied()).await?;
+        Ok(Self { listener })
+    }
+
+    // 接続が切れた場合、PgListenerが再接続してLISTENし直す
+    pub fn into_stream(self) -> impl Stream<Item = Result<Notification, sqlx::Error>> {
+        self.listener.into_stream().map(|result| {
+            result.map(|notification| Notification {
+                channel: notification.channel().to_string(),
+                payload: notification.payload().to_string(),
+            })
+        })
+    }
+}
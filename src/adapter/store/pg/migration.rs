@@ -0,0 +1,395 @@
+use sha2::{Digest, Sha256};
+use sqlx::{PgPool, Postgres, Transaction};
+use thiserror::Error;
+
+// 複数インスタンスが同時に起動してもマイグレーションが二重に適用されないようにする
+const MIGRATION_LOCK_KEY: i64 = 20_240_001;
+
+const APPLIED_MIGRATIONS_QUERY: &str =
+    "SELECT version, checksum FROM schema_migrations ORDER BY version";
+
+#[derive(Debug)]
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+    // migrate down で実行する
+    pub down: &'static str,
+    pub baseline: Option<Baseline>,
+}
+
+// 以前は db/init.sql で作成していたテーブル。期待する形で既に存在していれば、
+// SQLを実行せずに適用済みとして記録する
+#[derive(Debug)]
+pub struct Baseline {
+    pub table: &'static str,
+    pub columns: &'static [&'static str],
+    pub policy: &'static str,
+}
+
+impl Migration {
+    pub fn checksum(&self) -> String {
+        format!("{:x}", Sha256::digest(self.sql.as_bytes()))
+    }
+}
+
+// バージョン順に並べる。適用済みのファイルは編集せず、変更は新しいマイグレーションとして追加する
+pub static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_users",
+        sql: include_str!("../../../../migrations/0001_create_users.sql"),
+        down: include_str!("../../../../migrations/0001_create_users.down.sql"),
+        baseline: Some(Baseline {
+            table: "users",
+            columns: &["id", "tenant_id", "name", "email", "created_at"],
+            policy: "users_tenant_isolation",
+        }),
+    },
+    Migration {
+        version: 2,
+        name: "create_idempotency_keys",
+        sql: include_str!("../../../../migrations/0002_create_idempotency_keys.sql"),
+        down: include_str!("../../../../migrations/0002_create_idempotency_keys.down.sql"),
+        baseline: Some(Baseline {
+            table: "idempotency_keys",
+            columns: &["tenant_id", "key", "request_hash", "response", "created_at"],
+            policy: "idempotency_keys_tenant_isolation",
+        }),
+    },
+    Migration {
+        version: 3,
+        name: "users_created_at_timestamptz",
+        sql: include_str!("../../../../migrations/0003_users_created_at_timestamptz.sql"),
+        down: include_str!("../../../../migrations/0003_users_created_at_timestamptz.down.sql"),
+        baseline: None,
+    },
+    Migration {
+        version: 4,
+        name: "sync_users_id_sequence",
+        sql: include_str!("../../../../migrations/0004_sync_users_id_sequence.sql"),
+        down: include_str!("../../../../migrations/0004_sync_users_id_sequence.down.sql"),
+        baseline: None,
+    },
+    Migration {
+        version: 5,
+        name: "users_id_text",
+        sql: include_str!("../../../../migrations/0005_users_id_text.sql"),
+        down: include_str!("../../../../migrations/0005_users_id_text.down.sql"),
+        baseline: None,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+}
+
+#[derive(Debug)]
+pub struct MigrationStatus {
+    pub migration: &'static Migration,
+    pub state: MigrationState,
+}
+
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("Migration {version} ({name}) has been modified after it was applied")]
+    ChecksumMismatch { version: i64, name: String },
+
+    #[error("Migration {0} has been applied but is not known to this binary")]
+    UnknownVersion(i64),
+
+    #[error("Migration {version} ({name}) cannot be recorded as applied: table {table} already exists without the expected columns or policy")]
+    UnexpectedSchema {
+        version: i64,
+        name: String,
+        table: String,
+    },
+
+    #[error("Migration failed: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+pub struct Migrator {
+    pool: PgPool,
+}
+
+impl Migrator {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // schema_migrations が作成済みかどうか (一度もマイグレーションを実行していなければ false)
+    pub async fn is_initialised(&self) -> Result<bool, MigrationError> {
+        let (exists,): (bool,) =
+            sqlx::query_as("SELECT to_regclass('schema_migrations') IS NOT NULL")
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(exists)
+    }
+
+    async fn ensure_table(&self) -> Result<(), MigrationError> {
+        // 既に存在する場合に IF NOT EXISTS のNOTICEが毎回ログに出ないよう先に確認する
+        if self.is_initialised().await? {
+            return Ok(());
+        }
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version bigint PRIMARY KEY,
+                name text NOT NULL,
+                checksum char(64) NOT NULL,
+                applied_at timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )",
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // readyz からも呼ばれるため、テーブルの作成などの書き込みは行わない
+    pub async fn status(&self) -> Result<Vec<MigrationStatus>, MigrationError> {
+        let applied: Vec<(i64, String)> = if self.is_initialised().await? {
+            sqlx::query_as(APPLIED_MIGRATIONS_QUERY)
+                .fetch_all(&self.pool)
+                .await?
+        } else {
+            Vec::new()
+        };
+        verify(&applied)
+    }
+
+    pub async fn pending(&self) -> Result<Vec<&'static Migration>, MigrationError> {
+        Ok(self
+            .status()
+            .await?
+            .into_iter()
+            .filter(|status| status.state == MigrationState::Pending)
+            .map(|status| status.migration)
+            .collect())
+    }
+
+    // 未適用のマイグレーションを1件ずつ別のトランザクションで適用し、適用したものを返す
+    pub async fn run(&self) -> Result<Vec<&'static Migration>, MigrationError> {
+        self.ensure_table().await?;
+        let mut applied = Vec::new();
+        for migration in self.pending().await? {
+            let mut transaction = self.pool.begin().await?;
+            sqlx::query("SELECT pg_advisory_xact_lock($1)")
+                .bind(MIGRATION_LOCK_KEY)
+                .execute(&mut *transaction)
+                .await?;
+
+            // ロック待ちの間に他のインスタンスが適用・変更した場合に備え、ロックを取ってから検証し直す
+            let statuses = verify(
+                &sqlx::query_as(APPLIED_MIGRATIONS_QUERY)
+                    .fetch_all(&mut *transaction)
+                    .await?,
+            )?;
+            let already_applied = statuses.iter().any(|status| {
+                status.migration.version == migration.version
+                    && status.state == MigrationState::Applied
+            });
+            if already_applied {
+                transaction.rollback().await?;
+                continue;
+            }
+
+            if !Self::adopt(&mut transaction, migration).await? {
+                sqlx::raw_sql(migration.sql)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+            sqlx::query(
+                "INSERT INTO schema_migrations (version, name, checksum) VALUES ($1, $2, $3)",
+            )
+            .bind(migration.version)
+            .bind(migration.name)
+            .bind(migration.checksum())
+            .execute(&mut *transaction)
+            .await?;
+            transaction.commit().await?;
+            applied.push(migration);
+        }
+        Ok(applied)
+    }
+
+    // 以前の db/init.sql で作成済みのテーブルを、マイグレーションを実行せずに取り込む
+    // テーブルが無ければ false を返し、通常どおりマイグレーションを実行させる
+    async fn adopt(
+        transaction: &mut Transaction<'_, Postgres>,
+        migration: &Migration,
+    ) -> Result<bool, MigrationError> {
+        let Some(baseline) = &migration.baseline else {
+            return Ok(false);
+        };
+        let (exists,): (bool,) = sqlx::query_as("SELECT to_regclass($1) IS NOT NULL")
+            .bind(baseline.table)
+            .fetch_one(&mut **transaction)
+            .await?;
+        if !exists {
+            return Ok(false);
+        }
+
+        let columns: Vec<(String,)> = sqlx::query_as(
+            "SELECT column_name::text FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = $1",
+        )
+        .bind(baseline.table)
+        .fetch_all(&mut **transaction)
+        .await?;
+        let (has_policy,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS (SELECT 1 FROM pg_policies \
+             WHERE schemaname = current_schema() AND tablename = $1 AND policyname = $2)",
+        )
+        .bind(baseline.table)
+        .bind(baseline.policy)
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        let has_columns = baseline
+            .columns
+            .iter()
+            .all(|expected| columns.iter().any(|(column,)| column == expected));
+        if !has_columns || !has_policy {
+            return Err(MigrationError::UnexpectedSchema {
+                version: migration.version,
+                name: migration.name.to_string(),
+                table: baseline.table.to_string(),
+            });
+        }
+        Ok(true)
+    }
+
+    // 適用済みのマイグレーションを新しいものから steps 件取り消し、取り消したものを返す
+    pub async fn revert(&self, steps: usize) -> Result<Vec<&'static Migration>, MigrationError> {
+        let mut applied: Vec<_> = self
+            .status()
+            .await?
+            .into_iter()
+            .filter(|status| status.state == MigrationState::Applied)
+            .map(|status| status.migration)
+            .collect();
+        applied.reverse();
+        applied.truncate(steps);
+
+        let mut reverted = Vec::new();
+        for migration in applied {
+            let mut transaction = self.pool.begin().await?;
+            sqlx::query("SELECT pg_advisory_xact_lock($1)")
+                .bind(MIGRATION_LOCK_KEY)
+                .execute(&mut *transaction)
+                .await?;
+
+            let deleted: Option<(i64,)> = sqlx::query_as(
+                "DELETE FROM schema_migrations WHERE version = $1 RETURNING version",
+            )
+            .bind(migration.version)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            if deleted.is_none() {
+                transaction.rollback().await?;
+                continue;
+            }
+
+            sqlx::raw_sql(migration.down)
+                .execute(&mut *transaction)
+                .await?;
+            transaction.commit().await?;
+            reverted.push(migration);
+        }
+        Ok(reverted)
+    }
+}
+
+// 適用済みのマイグレーションのチェックサムを検証し、各マイグレーションの状態を返す
+fn verify(applied: &[(i64, String)]) -> Result<Vec<MigrationStatus>, MigrationError> {
+    if let Some((version, _)) = applied
+        .iter()
+        .find(|(version, _)| !MIGRATIONS.iter().any(|m| m.version == *version))
+    {
+        return Err(MigrationError::UnknownVersion(*version));
+    }
+
+    MIGRATIONS
+        .iter()
+        .map(|migration| {
+            match applied
+                .iter()
+                .find(|(version, _)| *version == migration.version)
+            {
+                Some((_, checksum)) if *checksum != migration.checksum() => {
+                    Err(MigrationError::ChecksumMismatch {
+                        version: migration.version,
+                        name: migration.name.to_string(),
+                    })
+                }
+                Some(_) => Ok(MigrationStatus {
+                    migration,
+                    state: MigrationState::Applied,
+                }),
+                None => Ok(MigrationStatus {
+                    migration,
+                    state: MigrationState::Pending,
+                }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn applied(versions: &[i64]) -> Vec<(i64, String)> {
+        MIGRATIONS
+            .iter()
+            .filter(|migration| versions.contains(&migration.version))
+            .map(|migration| (migration.version, migration.checksum()))
+            .collect()
+    }
+
+    #[test]
+    fn unapplied_migrations_are_pending() {
+        let states: Vec<_> = verify(&applied(&[1, 2]))
+            .unwrap()
+            .iter()
+            .map(|status| (status.migration.version, status.state))
+            .collect();
+        assert_eq!(states[0], (1, MigrationState::Applied));
+        assert_eq!(states[1], (2, MigrationState::Applied));
+        assert!(states[2..]
+            .iter()
+            .all(|(_, state)| *state == MigrationState::Pending));
+    }
+
+    #[test]
+    fn modified_migration_is_rejected() {
+        let mut applied = applied(&[1, 2]);
+        applied[1].1 = "0".repeat(64);
+        assert!(matches!(
+            verify(&applied),
+            Err(MigrationError::ChecksumMismatch { version: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_applied_version_is_rejected() {
+        let mut applied = applied(&[1]);
+        applied.push((999, "0".repeat(64)));
+        assert!(matches!(
+            verify(&applied),
+            Err(MigrationError::UnknownVersion(999))
+        ));
+    }
+
+    #[test]
+    fn only_tables_from_the_old_init_script_can_be_adopted() {
+        let adoptable: Vec<_> = MIGRATIONS
+            .iter()
+            .filter(|migration| migration.baseline.is_some())
+            .map(|migration| migration.version)
+            .collect();
+        assert_eq!(adoptable, [1, 2]);
+    }
+}
@@ -1,3 +1,9 @@
 pub mod command;
+pub mod id_generator;
+pub mod listener;
+pub mod migration;
+pub mod pool_router;
+pub mod query;
 pub mod sqlx_transaction;
+pub mod tenant_schema;
 pub mod transaction_manager;
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use crate::core::domain::query::user::{UserQuery, UserReadModel};
+use crate::core::domain::query::QueryError;
+use crate::core::domain::transaction::{Row, ToSql, TransactionWrapper};
+
+pub struct PgUserQuery;
+
+#[async_trait]
+impl UserQuery for PgUserQuery {
+    async fn find_by_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<Option<UserReadModel>, QueryError> {
+        let query = "SELECT id, name, email, created_at FROM users WHERE id = $1";
+        let params: Vec<Box<dyn ToSql>> = vec![Box::new(id.to_string()) as Box<dyn ToSql>];
+        let mut rows = transaction.fetch(query, params);
+        match rows.next().await {
+            Some(Ok(row)) => read_model_from_row(&row).map(Some),
+            Some(Err(e)) => Err(QueryError::DatabaseError(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    async fn list(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<Vec<UserReadModel>, QueryError> {
+        let query = "SELECT id, name, email, created_at FROM users ORDER BY created_at, id LIMIT $1 OFFSET $2";
+        let params: Vec<Box<dyn ToSql>> = vec![
+            Box::new(limit) as Box<dyn ToSql>,
+            Box::new(offset) as Box<dyn ToSql>,
+        ];
+        // 行はトランザクションから1行ずつ読み出す
+        let mut rows = transaction.fetch(query, params);
+        let mut users = Vec::new();
+        while let Some(row) = rows.next().await {
+            let row = row.map_err(|e| QueryError::DatabaseError(e.to_string()))?;
+            users.push(read_model_from_row(&row)?);
+        }
+        Ok(users)
+    }
+}
+
+fn read_model_from_row(row: &Row) -> Result<UserReadModel, QueryError> {
+    let (Some(id), Some(name), Some(email), Some(created_at)) = (
+        row.get_string("id"),
+        row.get_string("name"),
+        row.get_string("email"),
+        row.get_timestamp("created_at"),
+    ) else {
+        return Err(QueryError::MalformedRow("users".to_string()));
+    };
+    Ok(UserReadModel {
+        id,
+        name,
+        email,
+        created_at,
+    })
+}
@@ -1,49 +1,278 @@
-use crate::core::domain::transaction::{ToSql, TransactionError, TransactionWrapper};
+use crate::adapter::telemetry::sql_comment;
+use crate::core::domain::journal::{
+    RedactionPolicy, StatementJournal, StatementOutcome, StatementRecord,
+};
+use crate::core::domain::transaction::{
+    Row, SqlValue, ToSql, TransactionError, TransactionWrapper,
+};
+use crate::core::domain::transaction_context::TransactionContext;
 use async_trait::async_trait;
-use sqlx::{Postgres, Transaction};
+use chrono::NaiveDateTime;
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::query::Query;
+use sqlx::{Column, Postgres, Row as _, Transaction, TypeInfo, ValueRef};
+use std::time::Instant;
+use tracing::{info, instrument};
 
 pub struct SqlxTransaction<'t> {
     transaction: Transaction<'t, Postgres>,
+    // fetch のストリームが参照するため、コメントを付けたSQLを保持しておく
+    commented_query: String,
+    journal: StatementJournal,
+    redaction: RedactionPolicy,
+    log_statements: bool,
 }
 
 impl<'a> SqlxTransaction<'a> {
     pub fn new(transaction: Transaction<'a, Postgres>) -> Self {
-        Self { transaction }
+        Self {
+            transaction,
+            commented_query: String::new(),
+            journal: StatementJournal::default(),
+            redaction: RedactionPolicy::default(),
+            log_statements: false,
+        }
+    }
+
+    pub fn with_statement_journal(
+        mut self,
+        redaction: RedactionPolicy,
+        log_statements: bool,
+    ) -> Self {
+        self.redaction = redaction;
+        self.log_statements = log_statements;
+        self
+    }
+
+    // set_config(..., true) はSET LOCALと同じくトランザクション終了時にリセットされる
+    pub async fn apply_context(
+        &mut self,
+        context: &TransactionContext,
+    ) -> Result<(), TransactionError> {
+        for (key, value) in context.iter() {
+            sqlx::query("SELECT set_config($1, $2, true)")
+                .bind(key)
+                .bind(value)
+                .execute(&mut *self.transaction)
+                .await
+                .map_err(|e| {
+                    TransactionError::ExecutionError(format!(
+                        "Failed to apply transaction context: {}, error: {}",
+                        key,
+                        statement_failure(&e)
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+}
+
+fn bind_params<'q>(
+    mut sqlx_query: Query<'q, Postgres, PgArguments>,
+    params: Vec<Box<dyn ToSql>>,
+) -> Result<Query<'q, Postgres, PgArguments>, TransactionError> {
+    for (index, param) in params.into_iter().enumerate() {
+        if let Some(value) = param.as_i32() {
+            sqlx_query = sqlx_query.bind(value);
+        } else if let Some(value) = param.as_string() {
+            sqlx_query = sqlx_query.bind(value);
+        } else {
+            // 値は伏せ字の対象かもしれないため、エラーには位置だけを含める
+            return Err(TransactionError::BindError(format!(
+                "Unsupported parameter type: ${}",
+                index + 1
+            )));
+        }
+    }
+    Ok(sqlx_query)
+}
+
+// 対応していない型の列はクエリ側でキャストする (例: created_at::text)
+fn decode_row(row: &PgRow) -> Result<Row, TransactionError> {
+    let decode_error = |e: sqlx::Error| TransactionError::DecodeError(e.to_string());
+    let mut columns = Vec::with_capacity(row.len());
+
+    for column in row.columns() {
+        let index = column.ordinal();
+        let value = if row.try_get_raw(index).map_err(decode_error)?.is_null() {
+            SqlValue::Null
+        } else {
+            match column.type_info().name() {
+                "BOOL" => SqlValue::Bool(row.try_get(index).map_err(decode_error)?),
+                "INT2" => SqlValue::I32(row.try_get::<i16, _>(index).map_err(decode_error)?.into()),
+                "INT4" => SqlValue::I32(row.try_get(index).map_err(decode_error)?),
+                "INT8" => SqlValue::I64(row.try_get(index).map_err(decode_error)?),
+                "FLOAT4" => {
+                    SqlValue::F64(row.try_get::<f32, _>(index).map_err(decode_error)?.into())
+                }
+                "FLOAT8" => SqlValue::F64(row.try_get(index).map_err(decode_error)?),
+                "TEXT" | "VARCHAR" | "CHAR" | "NAME" => {
+                    SqlValue::String(row.try_get(index).map_err(decode_error)?)
+                }
+                "TIMESTAMPTZ" => SqlValue::Timestamp(row.try_get(index).map_err(decode_error)?),
+                // タイムゾーンの無い時刻はUTCとして扱う
+                "TIMESTAMP" => SqlValue::Timestamp(
+                    row.try_get::<NaiveDateTime, _>(index)
+                        .map_err(decode_error)?
+                        .and_utc(),
+                ),
+                other => {
+                    return Err(TransactionError::DecodeError(format!(
+                        "Unsupported column type: {} ({})",
+                        other,
+                        column.name()
+                    )))
+                }
+            }
+        };
+        columns.push((column.name().to_string(), value));
+    }
+
+    Ok(Row::new(columns))
+}
+
+// 実行した文をジャーナルに記録する。fetch ではストリームが読み終わるか破棄された時点で記録される
+struct PendingStatement<'j> {
+    journal: &'j mut StatementJournal,
+    log_statements: bool,
+    query: String,
+    params: Vec<String>,
+    started_at: Instant,
+    failure: Option<String>,
+}
+
+impl PendingStatement<'_> {
+    fn fail(&mut self, failure: String) {
+        self.failure.get_or_insert(failure);
+    }
+}
+
+impl Drop for PendingStatement<'_> {
+    fn drop(&mut self) {
+        let record = StatementRecord {
+            query: std::mem::take(&mut self.query),
+            params: std::mem::take(&mut self.params),
+            duration: self.started_at.elapsed(),
+            outcome: match self.failure.take() {
+                None => StatementOutcome::Success,
+                Some(failure) => StatementOutcome::Failure(failure),
+            },
+        };
+        if self.log_statements {
+            info!(
+                params = ?record.params,
+                duration = ?record.duration,
+                outcome = ?record.outcome,
+                "Executed statement"
+            );
+        }
+        self.journal.record(record);
+    }
+}
+
+// エラーのDETAILには制約に違反した値が含まれるため、SQLSTATEと主メッセージだけを残す
+// ジャーナルだけでなく、呼び出し元に返してログに出るエラーにもこれを使う
+fn statement_failure(error: &sqlx::Error) -> String {
+    match error.as_database_error() {
+        Some(database_error) => format!(
+            "SQLSTATE {}: {}",
+            database_error.code().unwrap_or_default(),
+            database_error.message()
+        ),
+        None => error.to_string(),
+    }
+}
+
+// トレースIDを含むコメントを付ける。コメントは毎回異なるため、プリペアドステートメントをキャッシュしない
+fn prepare<'q>(
+    query: &'q str,
+    commented_query: &'q mut String,
+) -> Query<'q, Postgres, PgArguments> {
+    match sql_comment() {
+        Some(comment) => {
+            *commented_query = format!("{} {}", query, comment);
+            sqlx::query(commented_query.as_str()).persistent(false)
+        }
+        None => sqlx::query(query),
     }
 }
 
 #[async_trait]
 impl<'t> TransactionWrapper for SqlxTransaction<'t> {
+    #[instrument(name = "statement", skip_all, fields(db.statement = query))]
     async fn execute(
         &mut self,
         query: &str,
         params: Vec<Box<dyn ToSql>>,
     ) -> Result<(), TransactionError> {
-        let mut sqlx_query = sqlx::query(query);
-
-        for param in params {
-            if let Some(value) = param.as_i32() {
-                sqlx_query = sqlx_query.bind(value);
-            } else if let Some(value) = param.as_string() {
-                sqlx_query = sqlx_query.bind(value);
-            } else {
-                return Err(TransactionError::BindError(format!(
-                    "Unsupported parameter type: {:?}",
-                    param
-                )));
-            }
-        }
+        let mut statement = PendingStatement {
+            params: self.redaction.redact(query, &params),
+            journal: &mut self.journal,
+            log_statements: self.log_statements,
+            query: query.to_string(),
+            started_at: Instant::now(),
+            failure: None,
+        };
 
+        let sqlx_query = bind_params(prepare(query, &mut self.commented_query), params)
+            .inspect_err(|e| statement.fail(e.to_string()))?;
         sqlx_query
             .execute(&mut *self.transaction)
             .await
+            .map(|_| ())
             .map_err(|e| {
+                let failure = statement_failure(&e);
+                statement.fail(failure.clone());
                 TransactionError::ExecutionError(format!(
-                    "Failed to execute query: {:?}, error: {:?}",
-                    query, e
+                    "Failed to execute query: {:?}, error: {}",
+                    query, failure
                 ))
-            })?;
-        Ok(())
+            })
+    }
+
+    fn fetch<'a>(
+        &'a mut self,
+        query: &'a str,
+        params: Vec<Box<dyn ToSql>>,
+    ) -> BoxStream<'a, Result<Row, TransactionError>> {
+        let mut statement = PendingStatement {
+            params: self.redaction.redact(query, &params),
+            journal: &mut self.journal,
+            log_statements: self.log_statements,
+            query: query.to_string(),
+            started_at: Instant::now(),
+            failure: None,
+        };
+
+        let sqlx_query = match bind_params(prepare(query, &mut self.commented_query), params) {
+            Ok(sqlx_query) => sqlx_query,
+            Err(e) => {
+                statement.fail(e.to_string());
+                return stream::once(async { Err(e) }).boxed();
+            }
+        };
+
+        // statement はストリームと一緒に破棄され、その時点の結果が記録される
+        sqlx_query
+            .fetch(&mut *self.transaction)
+            .map(move |result| {
+                let row = result.map_err(|e| {
+                    let failure = statement_failure(&e);
+                    statement.fail(failure.clone());
+                    TransactionError::ExecutionError(format!(
+                        "Failed to fetch query: {:?}, error: {}",
+                        query, failure
+                    ))
+                })?;
+                decode_row(&row).inspect_err(|e| statement.fail(e.to_string()))
+            })
+            .boxed()
+    }
+
+    fn take_journal(&mut self) -> StatementJournal {
+        std::mem::take(&mut self.journal)
     }
 
     async fn rollback(self: Box<Self>) -> Result<(), TransactionError> {
@@ -55,7 +284,75 @@ impl<'t> TransactionWrapper for SqlxTransaction<'t> {
 
     async fn commit(self: Box<Self>) -> Result<(), TransactionError> {
         self.transaction.commit().await.map_err(|e| {
-            TransactionError::CommitError(format!("Failed to commit transaction: {:?}", e))
+            TransactionError::CommitError(format!(
+                "Failed to commit transaction: {}",
+                statement_failure(&e)
+            ))
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::borrow::Cow;
+    use std::fmt;
+
+    // PgDatabaseError は外から作れないため、同じ内容 (DETAILを含む) を返す代わりのエラー
+    #[derive(Debug)]
+    struct UniqueViolation {
+        detail: &'static str,
+    }
+
+    impl fmt::Display for UniqueViolation {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} ({})", self.message(), self.detail)
+        }
+    }
+
+    impl std::error::Error for UniqueViolation {}
+
+    impl DatabaseError for UniqueViolation {
+        fn message(&self) -> &str {
+            "duplicate key value violates unique constraint \"users_email_key\""
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed("23505"))
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::UniqueViolation
+        }
+    }
+
+    #[test]
+    fn unique_violation_on_email_does_not_surface_the_value() {
+        let error = sqlx::Error::Database(Box::new(UniqueViolation {
+            detail: "Key (email)=(alice@example.com) already exists.",
+        }));
+        assert!(format!("{:?}", error).contains("alice@example.com"));
+
+        let failure = statement_failure(&error);
+        assert_eq!(
+            failure,
+            "SQLSTATE 23505: duplicate key value violates unique constraint \"users_email_key\""
+        );
+        // リポジトリは制約名でエラーを判別するため、制約名は残す
+        assert!(failure.contains("unique constraint"));
+        assert!(!failure.contains("alice@example.com"));
+    }
+}
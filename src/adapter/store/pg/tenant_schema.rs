@@ -0,0 +1,144 @@
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+use crate::core::domain::tenant::TenantId;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TenancyMode {
+    // public.users をRLSでテナント毎に分離する
+    #[default]
+    RowLevel,
+    // テナント毎のスキーマを search_path で切り替える
+    Schema,
+}
+
+impl FromStr for TenancyMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "row_level" => Ok(TenancyMode::RowLevel),
+            "schema" => Ok(TenancyMode::Schema),
+            other => Err(format!("Unknown tenancy mode: {}", other)),
+        }
+    }
+}
+
+// Postgresは63バイトを超える識別子を黙って切り詰めるため、これを超えないようにする
+const MAX_IDENTIFIER_LENGTH: usize = 63;
+const SCHEMA_PREFIX: &str = "tenant_";
+const HASH_LENGTH: usize = 15;
+
+// TenantIdは英数字・ハイフン・アンダースコアのみなので、引用符で囲めば安全な識別子になる
+pub fn schema_name(tenant_id: &str) -> String {
+    format!("\"{}\"", unquoted_schema_name(tenant_id))
+}
+
+// 長いテナントIDは先頭部分とハッシュで表す。TenantIdに含まれない '$' で区切るため、
+// 短いテナントIDのスキーマ名と衝突することはない
+fn unquoted_schema_name(tenant_id: &str) -> String {
+    let tenant_id = tenant_id.replace('"', "");
+    if SCHEMA_PREFIX.len() + tenant_id.len() <= MAX_IDENTIFIER_LENGTH {
+        return format!("{}{}", SCHEMA_PREFIX, tenant_id);
+    }
+    let hash = format!("{:x}", Sha256::digest(tenant_id.as_bytes()));
+    let prefix_length = MAX_IDENTIFIER_LENGTH - SCHEMA_PREFIX.len() - HASH_LENGTH - 1;
+    format!(
+        "{}{}${}",
+        SCHEMA_PREFIX,
+        &tenant_id[..prefix_length],
+        &hash[..HASH_LENGTH]
+    )
+}
+
+// テナント用スキーマに複製するテーブル
+const TENANT_TABLES: &[&str] = &["users", "idempotency_keys"];
+
+// public のテーブル定義を元にテナント用スキーマを作成する (既に存在する場合は何もしない)
+pub async fn create_tenant_schema(pool: &PgPool, tenant_id: &TenantId) -> Result<(), sqlx::Error> {
+    let schema = schema_name(tenant_id.as_str());
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query(&format!("CREATE SCHEMA IF NOT EXISTS {}", schema))
+        .execute(&mut *transaction)
+        .await?;
+    for table in TENANT_TABLES {
+        sqlx::query(&format!(
+            "CREATE TABLE IF NOT EXISTS {schema}.{table} (LIKE public.{table} INCLUDING ALL)"
+        ))
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    transaction.commit().await
+}
+
+// マイグレーションは public にのみ適用されるため、スキーマ作成後に追加されたマイグレーションは
+// テナントのテーブルに反映されない。public と列の定義 (型・長さ・NULL可否) が異なる列を返す
+pub async fn outdated_columns(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+) -> Result<Vec<String>, sqlx::Error> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "WITH columns AS (
+            SELECT table_schema, table_name, column_name, data_type,
+                   character_maximum_length, is_nullable
+            FROM information_schema.columns
+            WHERE table_schema IN ('public', $1) AND table_name = ANY($2)
+        ),
+        public_columns AS (
+            SELECT table_name, column_name, data_type, character_maximum_length, is_nullable
+            FROM columns WHERE table_schema = 'public'
+        ),
+        tenant_columns AS (
+            SELECT table_name, column_name, data_type, character_maximum_length, is_nullable
+            FROM columns WHERE table_schema = $1
+        )
+        SELECT DISTINCT table_name || '.' || column_name FROM (
+            (SELECT * FROM public_columns EXCEPT SELECT * FROM tenant_columns)
+            UNION ALL
+            (SELECT * FROM tenant_columns EXCEPT SELECT * FROM public_columns)
+        ) AS difference
+        ORDER BY 1",
+    )
+    .bind(unquoted_schema_name(tenant_id.as_str()))
+    .bind(TENANT_TABLES)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|(column,)| column).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unquoted(name: &str) -> &str {
+        name.trim_matches('"')
+    }
+
+    #[test]
+    fn short_tenant_ids_keep_a_readable_schema_name() {
+        assert_eq!(schema_name("acme"), "\"tenant_acme\"");
+        let id = "a".repeat(MAX_IDENTIFIER_LENGTH - SCHEMA_PREFIX.len());
+        assert_eq!(unquoted(&schema_name(&id)), format!("tenant_{}", id));
+    }
+
+    #[test]
+    fn long_tenant_ids_fit_in_an_identifier() {
+        let id = "a".repeat(64);
+        let name = schema_name(&id);
+        assert_eq!(unquoted(&name).len(), MAX_IDENTIFIER_LENGTH);
+        assert_eq!(name, schema_name(&id));
+    }
+
+    #[test]
+    fn long_tenant_ids_sharing_a_prefix_get_distinct_schemas() {
+        let prefix = "a".repeat(60);
+        assert_ne!(
+            schema_name(&format!("{}-one", prefix)),
+            schema_name(&format!("{}-two", prefix))
+        );
+    }
+}
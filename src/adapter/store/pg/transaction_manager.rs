@@ -1,45 +1,307 @@
+use crate::adapter::metrics::{
+    TRANSACTIONS_COMMITTED_TOTAL, TRANSACTIONS_ROLLED_BACK_TOTAL, TRANSACTION_DURATION_SECONDS,
+    TRANSACTION_RETRIES_TOTAL,
+};
+use crate::adapter::store::pg::pool_router::{PgPoolRouter, ReplicaSelection};
 use crate::adapter::store::pg::sqlx_transaction::SqlxTransaction;
+use crate::adapter::store::pg::tenant_schema::{schema_name, TenancyMode};
 use async_trait::async_trait;
 use sqlx::PgPool;
+use std::future::Future;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{info_span, instrument, warn, Instrument};
 
-use crate::core::domain::transaction::{TransactionError, TransactionWrapper};
+use crate::core::domain::journal::RedactionPolicy;
+use crate::core::domain::transaction::{AccessMode, TransactionError, TransactionWrapper};
+use crate::core::domain::transaction_context::{TransactionContext, TENANT_ID_KEY};
 use crate::core::domain::transaction_manager::{TransactionManager, TransactionManagerError};
-use crate::core::domain::transaction_operation::{BoxedTransactionOperation, TransactionOperationError};
+use crate::core::domain::transaction_operation::BoxedTransactionOperation;
 
 pub struct PgTransactionManager {
-    pool: PgPool,
+    pools: PgPoolRouter,
+    tenancy: TenancyMode,
+    redaction: RedactionPolicy,
+    log_statements: bool,
+    max_retries: u32,
+    cancellation: CancellationToken,
 }
 
 impl PgTransactionManager {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pools: PgPoolRouter::new(pool),
+            tenancy: TenancyMode::default(),
+            redaction: RedactionPolicy::default(),
+            log_statements: false,
+            max_retries: 0,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_replicas(self, replicas: Vec<PgPool>, selection: ReplicaSelection) -> Self {
+        Self {
+            pools: self.pools.with_replicas(replicas, selection),
+            ..self
+        }
+    }
+
+    pub fn with_tenancy(self, tenancy: TenancyMode) -> Self {
+        Self { tenancy, ..self }
+    }
+
+    pub fn with_statement_journal(self, redaction: RedactionPolicy, log_statements: bool) -> Self {
+        Self {
+            redaction,
+            log_statements,
+            ..self
+        }
+    }
+
+    // 同時実行の競合で失敗した作業単位を最大max_retries回まで最初からやり直す
+    pub fn with_max_retries(self, max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..self
+        }
+    }
+
+    // トークンがキャンセルされると実行中の作業単位をロールバックし、新しい作業単位も開始しない
+    pub fn with_cancellation(self, cancellation: CancellationToken) -> Self {
+        Self {
+            cancellation,
+            ..self
+        }
+    }
+
+    // スキーマ分離モードではテナントのスキーマだけが見えるよう search_path を切り替える
+    fn resolve_context(
+        &self,
+        context: TransactionContext,
+    ) -> Result<TransactionContext, TransactionManagerError> {
+        match self.tenancy {
+            TenancyMode::RowLevel => Ok(context),
+            TenancyMode::Schema => {
+                let schema = context.get(TENANT_ID_KEY).map(schema_name).ok_or_else(|| {
+                    TransactionManagerError::BeginError(
+                        "Tenant id is required in schema tenancy mode".to_string(),
+                    )
+                })?;
+                Ok(context.with("search_path", schema))
+            }
+        }
+    }
+
+    async fn execute_once(
+        &self,
+        context: &TransactionContext,
+        operation: &dyn BoxedTransactionOperation,
+    ) -> Result<(), TransactionManagerError> {
+        let access_mode = operation.access_mode();
+        let mut sqlx_transaction = self
+            .pools
+            .begin(access_mode)
+            .instrument(info_span!("begin"))
+            .await
+            .map_err(|e| {
+                TransactionManagerError::from(TransactionError::ConnectionError(e.to_string()))
+            })?;
+
+        // プライマリへフォールバックした場合でも書き込みを防ぐ
+        if access_mode == AccessMode::ReadOnly {
+            sqlx::query("SET TRANSACTION READ ONLY")
+                .execute(&mut *sqlx_transaction)
+                .await
+                .map_err(|e| TransactionManagerError::BeginError(e.to_string()))?;
+        }
+
+        let mut sqlx_transaction = SqlxTransaction::new(sqlx_transaction)
+            .with_statement_journal(self.redaction.clone(), self.log_statements);
+        sqlx_transaction.apply_context(context).await?;
+
+        let mut transaction: Box<dyn TransactionWrapper> = Box::new(sqlx_transaction);
+
+        let result = tokio::select! {
+            result = operation.execute(&mut transaction) => result,
+            _ = self.cancellation.cancelled() => Err(TransactionError::Cancelled(
+                "Unit of work aborted by shutdown".to_string(),
+            )
+            .into()),
+        };
+        let journal = transaction.take_journal();
+        match result {
+            Ok(result) => {
+                if let Err(source) = transaction.commit().instrument(info_span!("commit")).await {
+                    return Err(TransactionManagerError::TransactionError { source, journal });
+                }
+                Ok(result)
+            }
+            Err(source) => {
+                warn!(error = %source, "Rolling back transaction");
+                if let Err(rollback_err) = transaction
+                    .rollback()
+                    .instrument(info_span!("rollback"))
+                    .await
+                {
+                    return Err(TransactionManagerError::TransactionError {
+                        source: rollback_err,
+                        journal,
+                    });
+                }
+                Err(TransactionManagerError::OperationError { source, journal })
+            }
+        }
     }
 }
 #[async_trait]
 impl TransactionManager for PgTransactionManager {
-    async fn execute(
+    #[instrument(
+        name = "transaction",
+        skip_all,
+        fields(operation = operation.operation_type(), access_mode = ?operation.access_mode())
+    )]
+    async fn execute_with_context(
         &self,
+        context: TransactionContext,
         operation: Box<dyn BoxedTransactionOperation>,
     ) -> Result<(), TransactionManagerError> {
-        let mut transaction: Box<dyn TransactionWrapper> = Box::new(SqlxTransaction::new(
-            self.pool.begin().await.map_err(|e| {
-                TransactionManagerError::TransactionError(TransactionError::ConnectionError(
-                    e.to_string(),
-                ))
-            })?,
-        ));
+        if self.cancellation.is_cancelled() {
+            return Err(TransactionError::Cancelled("Shutting down".to_string()).into());
+        }
+        let context = self.resolve_context(context)?;
+        let operation_type = operation.operation_type();
+        let started_at = Instant::now();
 
-        match operation.execute(&mut transaction).await {
-            Ok(result) => {
-                transaction.commit().await?;
-                Ok(result)
+        let result = retry_on_conflict(self.max_retries, operation_type, || {
+            self.execute_once(&context, operation.as_ref())
+        })
+        .await;
+
+        let (counter, outcome) = match result {
+            Ok(_) => (TRANSACTIONS_COMMITTED_TOTAL, "commit"),
+            Err(_) => (TRANSACTIONS_ROLLED_BACK_TOTAL, "rollback"),
+        };
+        metrics::counter!(counter, "operation" => operation_type).increment(1);
+        metrics::histogram!(
+            TRANSACTION_DURATION_SECONDS,
+            "operation" => operation_type,
+            "outcome" => outcome
+        )
+        .record(started_at.elapsed().as_secs_f64());
+
+        result
+    }
+}
+
+// 同時実行の競合で失敗した場合だけ、最大max_retries回まで作業単位を最初からやり直す
+async fn retry_on_conflict<F, Fut>(
+    max_retries: u32,
+    operation_type: &'static str,
+    mut execute_once: F,
+) -> Result<(), TransactionManagerError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), TransactionManagerError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match execute_once().await {
+            Err(e) if e.is_retryable() && attempt < max_retries => {
+                attempt += 1;
+                metrics::counter!(TRANSACTION_RETRIES_TOTAL, "operation" => operation_type)
+                    .increment(1);
+                warn!(error = %e, attempt, "Retrying transaction");
             }
-            Err(e) => {
-                if let Err(rollback_err) = transaction.rollback().await {
-                    return Err(TransactionManagerError::TransactionError(rollback_err))
+            result => break result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::command::CommandError;
+    use crate::core::domain::journal::StatementJournal;
+    use crate::core::domain::transaction_operation::TransactionOperationError;
+    use std::cell::Cell;
+
+    fn operation_error(error: CommandError) -> TransactionManagerError {
+        TransactionManagerError::OperationError {
+            source: TransactionOperationError::CommandError(error),
+            journal: StatementJournal::default(),
+        }
+    }
+
+    fn conflict() -> TransactionManagerError {
+        operation_error(CommandError::ConcurrencyError {
+            entity_type: "User".to_string(),
+        })
+    }
+
+    // 最初のfailures回だけmake_errorのエラーで失敗する作業単位
+    async fn run(
+        max_retries: u32,
+        failures: u32,
+        make_error: fn() -> TransactionManagerError,
+    ) -> (Result<(), TransactionManagerError>, u32) {
+        let calls = Cell::new(0);
+        let result = retry_on_conflict(max_retries, "Test", || {
+            calls.set(calls.get() + 1);
+            let call = calls.get();
+            async move {
+                if call <= failures {
+                    Err(make_error())
+                } else {
+                    Ok(())
                 }
-                Err(TransactionManagerError::OperationError(e))
             }
-        }
+        })
+        .await;
+        (result, calls.get())
+    }
+
+    #[tokio::test]
+    async fn conflicts_are_retried_until_the_operation_succeeds() {
+        let (result, calls) = run(3, 2, conflict).await;
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_max_retries() {
+        let (result, calls) = run(2, 5, conflict).await;
+        assert!(result.unwrap_err().is_retryable());
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn conflicts_are_not_retried_by_default() {
+        let (result, calls) = run(0, 1, conflict).await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_not_retried() {
+        let already_exists = || {
+            operation_error(CommandError::AlreadyExists {
+                entity_type: "User".to_string(),
+                details: "email is already in use".to_string(),
+            })
+        };
+        let (result, calls) = run(3, 1, already_exists).await;
+        assert!(matches!(
+            result.unwrap_err().command_error(),
+            Some(CommandError::AlreadyExists { .. })
+        ));
+        assert_eq!(calls, 1);
+
+        let begin_error = || TransactionManagerError::BeginError("connection refused".to_string());
+        let (result, calls) = run(3, 1, begin_error).await;
+        assert!(matches!(
+            result,
+            Err(TransactionManagerError::BeginError(_))
+        ));
+        assert_eq!(calls, 1);
     }
 }
@@ -1,11 +1,25 @@
+use sqlx::PgPool;
 use std::sync::Arc;
 
-use crate::core::domain::entity::user::UserCommand;
-use crate::core::domain::transaction_manager::TransactionManager;
+use crate::adapter::health::ReadinessCheck;
+use crate::adapter::metrics::MetricsExporter;
 use crate::core::port::create_user::CreateUserInputBoundary;
+use crate::core::port::delete_user::DeleteUserInputBoundary;
+use crate::core::port::get_user::GetUserInputBoundary;
+use crate::core::port::list_users::ListUsersInputBoundary;
+use crate::core::port::update_user::UpdateUserInputBoundary;
 
 pub struct AppState {
-    pub transaction_manager: Arc<dyn TransactionManager>,
-    pub create_user_repository: Arc<dyn UserCommand>,
     pub user_create_use_case: Arc<dyn CreateUserInputBoundary>,
+    pub user_get_use_case: Arc<dyn GetUserInputBoundary>,
+    pub user_list_use_case: Arc<dyn ListUsersInputBoundary>,
+    pub user_update_use_case: Arc<dyn UpdateUserInputBoundary>,
+    pub user_delete_use_case: Arc<dyn DeleteUserInputBoundary>,
+    pub metrics: Arc<MetricsExporter>,
+    pub readiness: Arc<ReadinessCheck>,
+    // クライアントがIDを指定する場合に必要なトークン (未設定なら誰も指定できない)
+    pub admin_token: Option<String>,
+    // シャットダウン時に閉じる
+    pub pool: PgPool,
+    pub replicas: Vec<PgPool>,
 }
@@ -1,12 +1,41 @@
-use axum::routing::post;
+use axum::extract::Request;
+use axum::middleware;
+use axum::handler::Handler;
+use axum::routing::{get, post};
 use axum::Router;
 use std::sync::Arc;
+use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, MakeSpan, TraceLayer};
+use tracing::Level;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
+use crate::adapter::telemetry::extract_trace_context;
 use crate::adapter::web::app_state::AppState;
-use crate::adapter::web::route::users;
+use crate::adapter::web::middleware::metrics::track_metrics;
+use crate::adapter::web::route::{fallback, health, metrics, users};
 
 pub fn create_router(state: Arc<AppState>) -> Router {
     Router::new()
-        .route("/users", post(users::post))
+        .route("/users", post(users::post).get(users::list))
+        .route(
+            "/users/:id",
+            get(users::get).patch(users::patch).delete(users::delete),
+        )
+        .route("/metrics", get(metrics::get))
+        .route("/healthz", get(health::healthz))
+        .route("/readyz", get(health::readyz))
+        // メソッドのフォールバックはルートの一部として route_layer の対象になる
+        .method_not_allowed_fallback(fallback::method_not_allowed)
+        .route_layer(middleware::from_fn(track_metrics))
+        // ルートに一致しないリクエストには route_layer が適用されないため、404 も数えるよう個別に付ける
+        .fallback(fallback::not_found.layer(middleware::from_fn(track_metrics)))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(|request: &Request| {
+                    let span = DefaultMakeSpan::new().level(Level::INFO).make_span(request);
+                    span.set_parent(extract_trace_context(request.headers()));
+                    span
+                })
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
+        )
         .with_state(state)
 }
@@ -11,9 +11,12 @@ impl From<CreateUserWebInput> for UnvalidatedCreateUserInput {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+// 省略した項目は空として受け取り、他の項目と一緒にドメインの検証で報告する
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(default)]
 pub struct CreateUserWebInput {
-    pub id: i32,
+    // 管理用途でIDを指定する場合のみ (X-Admin-Token と server.allow_client_ids が必要)
+    pub id: Option<String>,
     pub name: String,
     pub email: String,
 }
@@ -0,0 +1,12 @@
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ListUsersWebOutput {
+    pub items: Vec<UserWebOutput>,
+    pub limit: u32,
+    pub offset: u32,
+    // 次のページが無い場合は省略する
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u32>,
+}
@@ -0,0 +1,64 @@
+use crate::core::domain::entity::user::value::FieldViolation;
+use crate::core::port::list_users::ListUsersInput;
+use serde::Deserialize;
+
+const DEFAULT_LIMIT: u32 = 20;
+
+impl TryFrom<ListUsersWebQuery> for ListUsersInput {
+    type Error = Vec<FieldViolation>;
+
+    fn try_from(value: ListUsersWebQuery) -> Result<Self, Self::Error> {
+        let limit = parse_number("limit", value.limit, DEFAULT_LIMIT);
+        let offset = parse_number("offset", value.offset, 0);
+        match (limit, offset) {
+            (Ok(limit), Ok(offset)) => Ok(Self { limit, offset }),
+            (limit, offset) => Err([limit.err(), offset.err()].into_iter().flatten().collect()),
+        }
+    }
+}
+
+fn parse_number(
+    field: &'static str,
+    value: Option<String>,
+    default: u32,
+) -> Result<u32, FieldViolation> {
+    value.map_or(Ok(default), |value| {
+        value
+            .parse()
+            .map_err(|_| FieldViolation::new(field, "must be a non-negative integer"))
+    })
+}
+
+// 数値でない値も項目毎のエラーとして返せるよう、文字列のまま受け取る
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListUsersWebQuery {
+    pub limit: Option<String>,
+    pub offset: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(limit: Option<&str>, offset: Option<&str>) -> ListUsersWebQuery {
+        ListUsersWebQuery {
+            limit: limit.map(str::to_string),
+            offset: offset.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn omitted_values_fall_back_to_defaults() {
+        let input = ListUsersInput::try_from(query(None, None)).unwrap();
+        assert_eq!((input.limit, input.offset), (DEFAULT_LIMIT, 0));
+        let input = ListUsersInput::try_from(query(Some("5"), Some("10"))).unwrap();
+        assert_eq!((input.limit, input.offset), (5, 10));
+    }
+
+    #[test]
+    fn non_numeric_values_are_reported_per_field() {
+        let violations = ListUsersInput::try_from(query(Some("abc"), Some("-1"))).unwrap_err();
+        let fields: Vec<_> = violations.iter().map(|violation| violation.field).collect();
+        assert_eq!(fields, ["limit", "offset"]);
+    }
+}
@@ -1 +1,5 @@
 pub mod create_user_web_input;
+pub mod list_users_web_output;
+pub mod list_users_web_query;
+pub mod update_user_web_input;
+pub mod user_web_output;
@@ -0,0 +1,18 @@
+use crate::core::port::update_user::UpdateUserInput;
+use serde::Deserialize;
+
+impl From<UpdateUserWebInput> for UpdateUserInput {
+    fn from(value: UpdateUserWebInput) -> Self {
+        Self {
+            name: value.name,
+            email: value.email,
+        }
+    }
+}
+
+// 省略した項目は変更しない
+#[derive(Debug, Deserialize, Clone)]
+pub struct UpdateUserWebInput {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
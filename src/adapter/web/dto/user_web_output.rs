@@ -0,0 +1,22 @@
+use crate::core::domain::query::user::UserReadModel;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+impl From<UserReadModel> for UserWebOutput {
+    fn from(value: UserReadModel) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            email: value.email,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UserWebOutput {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+}
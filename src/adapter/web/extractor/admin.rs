@@ -0,0 +1,40 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+use crate::adapter::web::app_state::AppState;
+use crate::adapter::web::problem::Problem;
+
+pub const ADMIN_TOKEN_HEADER: &str = "x-admin-token";
+
+// 管理者向けの操作を許可するかどうか。ヘッダーが無ければ通常のリクエストとして扱う
+pub struct Admin(pub bool);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for Admin {
+    type Rejection = Problem;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(ADMIN_TOKEN_HEADER) else {
+            return Ok(Admin(false));
+        };
+        // トークンが設定されていなければ、どのリクエストも管理者として扱わない
+        match state.admin_token.as_deref() {
+            Some(token) if constant_time_eq(value.as_bytes(), token.as_bytes()) => Ok(Admin(true)),
+            _ => Err(Problem::new(
+                StatusCode::UNAUTHORIZED,
+                "Invalid admin token",
+            )),
+        }
+    }
+}
+
+// 比較にかかる時間からトークンを推測されないよう、一致する長さに関わらず全体を比較する
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
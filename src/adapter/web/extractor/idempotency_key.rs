@@ -0,0 +1,36 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::idempotency::IdempotencyKey;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+// ヘッダーが無い場合は冪等性を保証しない通常のリクエストとして扱う
+pub struct OptionalIdempotencyKey(pub Option<IdempotencyKey>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for OptionalIdempotencyKey
+where
+    S: Send + Sync,
+{
+    type Rejection = Problem;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(IDEMPOTENCY_KEY_HEADER) else {
+            return Ok(OptionalIdempotencyKey(None));
+        };
+        let value = value.to_str().map_err(|e| {
+            Problem::new(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid idempotency key header: {}", e),
+            )
+        })?;
+
+        IdempotencyKey::try_from(value.to_string())
+            .map(|key| OptionalIdempotencyKey(Some(key)))
+            .map_err(|e| Problem::new(StatusCode::BAD_REQUEST, e.to_string()))
+    }
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use axum::async_trait;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRequest, Request};
+use serde::de::DeserializeOwned;
+
+use crate::adapter::web::problem::{Problem, ProblemFieldError};
+
+// axum::Json と同じだが、拒否した理由を problem+json で返す
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Problem;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::Json::<T>::from_request(request, state).await {
+            Ok(axum::Json(value)) => Ok(Json(value)),
+            // 形式は正しいが型が合わない場合は、どの項目が不正かを返す
+            Err(JsonRejection::JsonDataError(rejection)) => {
+                Err(Problem::invalid_fields(vec![field_error(&rejection)]))
+            }
+            Err(rejection) => Err(Problem::new(rejection.status(), rejection.body_text())),
+        }
+    }
+}
+
+fn field_error(rejection: &(dyn Error + 'static)) -> ProblemFieldError {
+    let mut source = rejection.source();
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<serde_path_to_error::Error<serde_json::Error>>() {
+            // 項目の途中ではなくボディ全体の形が合わない場合、パスは "." になる
+            let field = match error.path().to_string() {
+                path if path == "." => "body".to_string(),
+                path => path,
+            };
+            return ProblemFieldError {
+                field,
+                message: error.inner().to_string(),
+            };
+        }
+        source = error.source();
+    }
+    ProblemFieldError {
+        field: "body".to_string(),
+        message: rejection.to_string(),
+    }
+}
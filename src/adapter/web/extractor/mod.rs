@@ -0,0 +1,6 @@
+pub mod admin;
+pub mod idempotency_key;
+pub mod json;
+pub mod path;
+pub mod query;
+pub mod tenant;
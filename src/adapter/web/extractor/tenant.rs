@@ -0,0 +1,39 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::tenant::TenantId;
+
+pub const TENANT_ID_HEADER: &str = "x-tenant-id";
+
+// テナントが無いリクエストはトランザクション開始前にここで拒否する
+pub struct Tenant(pub TenantId);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Tenant
+where
+    S: Send + Sync,
+{
+    type Rejection = Problem;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let value = parts
+            .headers
+            .get(TENANT_ID_HEADER)
+            .map(|value| value.to_str().map(str::to_string))
+            .transpose()
+            .map_err(|e| {
+                Problem::new(
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid tenant header: {}", e),
+                )
+            })?
+            .unwrap_or_default();
+
+        TenantId::try_from(value)
+            .map(Tenant)
+            .map_err(|e| Problem::new(StatusCode::BAD_REQUEST, e.to_string()))
+    }
+}
@@ -0,0 +1,34 @@
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::delete_user::DeleteUserInputBoundary;
+
+use crate::adapter::web::presenter::delete_user::DeleteUserPresenter;
+use crate::adapter::web::problem::Problem;
+
+pub struct DeleteUserHandler {
+    use_case: Arc<dyn DeleteUserInputBoundary>,
+}
+
+impl DeleteUserHandler {
+    pub fn new(use_case: Arc<dyn DeleteUserInputBoundary>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn delete_user(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+    ) -> Result<StatusCode, Problem> {
+        let mut presenter = DeleteUserPresenter::new();
+
+        match self.use_case.execute(tenant_id, id, &mut presenter).await {
+            Ok(_) => match presenter.output.take() {
+                Some(id) => presenter.success(id),
+                None => Err(Problem::internal(&"Output not set by presenter")),
+            },
+            Err(error) => Err(presenter.failure(error)),
+        }
+    }
+}
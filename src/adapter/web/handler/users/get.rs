@@ -0,0 +1,35 @@
+use axum::Json;
+use std::sync::Arc;
+
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::get_user::GetUserInputBoundary;
+
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::presenter::get_user::GetUserPresenter;
+use crate::adapter::web::problem::Problem;
+
+pub struct GetUserHandler {
+    use_case: Arc<dyn GetUserInputBoundary>,
+}
+
+impl GetUserHandler {
+    pub fn new(use_case: Arc<dyn GetUserInputBoundary>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn get_user(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+    ) -> Result<Json<UserWebOutput>, Problem> {
+        let mut presenter = GetUserPresenter::new();
+
+        match self.use_case.execute(tenant_id, id, &mut presenter).await {
+            Ok(_) => match presenter.output.take() {
+                Some(user) => presenter.success(user),
+                None => Err(Problem::internal(&"Output not set by presenter")),
+            },
+            Err(error) => Err(presenter.failure(error)),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use axum::Json;
+use std::sync::Arc;
+
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::list_users::{ListUsersInput, ListUsersInputBoundary};
+
+use crate::adapter::web::dto::list_users_web_output::ListUsersWebOutput;
+use crate::adapter::web::dto::list_users_web_query::ListUsersWebQuery;
+use crate::adapter::web::presenter::list_users::ListUsersPresenter;
+use crate::adapter::web::problem::Problem;
+
+pub struct ListUsersHandler {
+    use_case: Arc<dyn ListUsersInputBoundary>,
+}
+
+impl ListUsersHandler {
+    pub fn new(use_case: Arc<dyn ListUsersInputBoundary>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn list_users(
+        &self,
+        tenant_id: TenantId,
+        query: ListUsersWebQuery,
+    ) -> Result<Json<ListUsersWebOutput>, Problem> {
+        let mut presenter = ListUsersPresenter::new();
+        let input = ListUsersInput::try_from(query)
+            .map_err(|violations| Problem::validation(&violations))?;
+
+        match self
+            .use_case
+            .execute(tenant_id, input, &mut presenter)
+            .await
+        {
+            Ok(_) => match presenter.output.take() {
+                Some(users) => presenter.success(input, users),
+                None => Err(Problem::internal(&"Output not set by presenter")),
+            },
+            Err(error) => Err(presenter.failure(error)),
+        }
+    }
+}
@@ -1 +1,5 @@
+pub mod delete;
+pub mod get;
+pub mod list;
+pub mod patch;
 pub mod post;
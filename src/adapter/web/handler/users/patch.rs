@@ -0,0 +1,42 @@
+use axum::Json;
+use std::sync::Arc;
+
+use crate::core::domain::tenant::TenantId;
+use crate::core::port::update_user::{UpdateUserInput, UpdateUserInputBoundary};
+
+use crate::adapter::web::dto::update_user_web_input::UpdateUserWebInput;
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::presenter::update_user::UpdateUserPresenter;
+use crate::adapter::web::problem::Problem;
+
+pub struct UpdateUserHandler {
+    use_case: Arc<dyn UpdateUserInputBoundary>,
+}
+
+impl UpdateUserHandler {
+    pub fn new(use_case: Arc<dyn UpdateUserInputBoundary>) -> Self {
+        Self { use_case }
+    }
+
+    pub async fn update_user(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        user: UpdateUserWebInput,
+    ) -> Result<Json<UserWebOutput>, Problem> {
+        let mut presenter = UpdateUserPresenter::new();
+        let input = UpdateUserInput::from(user);
+
+        match self
+            .use_case
+            .execute(tenant_id, id, input, &mut presenter)
+            .await
+        {
+            Ok(_) => match presenter.output.take() {
+                Some(user) => presenter.success(user),
+                None => Err(Problem::internal(&"Output not set by presenter")),
+            },
+            Err(error) => Err(presenter.failure(error)),
+        }
+    }
+}
@@ -2,10 +2,13 @@ use axum::http::StatusCode;
 use std::sync::Arc;
 
 use crate::core::domain::entity::user::user::UnvalidatedCreateUserInput;
+use crate::core::domain::idempotency::IdempotencyKey;
+use crate::core::domain::tenant::TenantId;
 use crate::core::port::create_user::CreateUserInputBoundary;
 
 use crate::adapter::web::dto::create_user_web_input::CreateUserWebInput;
-use crate::adapter::web::presenter::create_user::CreateUserPresenter;
+use crate::adapter::web::presenter::create_user::{CreateUserPresenter, CreatedUser};
+use crate::adapter::web::problem::Problem;
 
 pub struct UserHandler {
     use_case: Arc<dyn CreateUserInputBoundary>,
@@ -18,20 +21,31 @@ impl UserHandler {
 
     pub async fn create_user(
         &self,
+        tenant_id: TenantId,
+        is_admin: bool,
+        idempotency_key: Option<IdempotencyKey>,
         user: CreateUserWebInput,
-    ) -> Result<StatusCode, (StatusCode, String)> {
+    ) -> Result<CreatedUser, Problem> {
+        // IDの指定は管理者のリクエストに限る。許可するかどうかはさらにユースケースが設定で判断する
+        if user.id.is_some() && !is_admin {
+            return Err(Problem::new(
+                StatusCode::FORBIDDEN,
+                "Supplying a user id requires an admin token",
+            ));
+        }
         let mut presenter = CreateUserPresenter::new();
         let input = UnvalidatedCreateUserInput::from(user);
 
-        match self.use_case.execute(input, &mut presenter).await {
+        match self
+            .use_case
+            .execute(tenant_id, input, idempotency_key, &mut presenter)
+            .await
+        {
             Ok(_) => {
-                if let Some(id) = presenter.output {
-                    presenter.success(id)
+                if let Some(user) = presenter.output.take() {
+                    presenter.success(user)
                 } else {
-                    Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        "Output not set by presenter".to_string(),
-                    ))
+                    Err(Problem::internal(&"Output not set by presenter"))
                 }
             }
             Err(error) => Err(presenter.failure(error)),
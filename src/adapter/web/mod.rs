@@ -1,6 +1,9 @@
 pub mod app_state;
 pub mod create_router;
 pub mod dto;
+pub mod extractor;
 pub mod handler;
+pub mod middleware;
 pub mod presenter;
+pub mod problem;
 pub mod route;
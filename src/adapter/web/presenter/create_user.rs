@@ -1,30 +1,45 @@
-use axum::http::StatusCode;
+use axum::http::header::LOCATION;
+use axum::http::{HeaderName, StatusCode};
+use axum::Json;
 
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::query::user::UserReadModel;
 use crate::core::port::create_user::{
     CreateUserError, CreateUserOutputBoundary, CreateUserOutputError,
 };
 
+// 201 Created と、作成したユーザーを指す Location ヘッダーを返す
+pub(crate) type CreatedUser = (StatusCode, [(HeaderName, String); 1], Json<UserWebOutput>);
+
 pub struct CreateUserPresenter {
-    pub(crate) output: Option<i32>,
+    pub(crate) output: Option<UserReadModel>,
 }
 
 impl CreateUserPresenter {
     pub fn new() -> Self {
         Self { output: None }
     }
-    pub(crate) fn success(&self, output: i32) -> Result<StatusCode, (StatusCode, String)> {
-        Ok(StatusCode::CREATED)
+    pub(crate) fn success(&self, output: UserReadModel) -> Result<CreatedUser, Problem> {
+        let location = format!("/users/{}", output.id);
+        Ok((
+            StatusCode::CREATED,
+            [(LOCATION, location)],
+            Json(UserWebOutput::from(output)),
+        ))
     }
-    pub(crate) fn failure(&self, error: CreateUserError) -> (StatusCode, String) {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Failed to create user: {:?}", error),
-        )
+    pub(crate) fn failure(&self, error: CreateUserError) -> Problem {
+        match &error {
+            CreateUserError::ValidationError(error) => Problem::validation(error.violations()),
+            CreateUserError::CommandError(error) => Problem::from_command_error(error),
+            CreateUserError::TransactionError(error) => Problem::from_transaction_error(error),
+            CreateUserError::OutputError(_) => Problem::internal(&error),
+        }
     }
 }
 
 impl CreateUserOutputBoundary for CreateUserPresenter {
-    fn execute(&mut self, output: i32) -> Result<(), CreateUserOutputError> {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), CreateUserOutputError> {
         self.output = Some(output);
         Ok(())
     }
@@ -0,0 +1,33 @@
+use axum::http::StatusCode;
+
+use crate::adapter::web::problem::Problem;
+use crate::core::port::delete_user::{
+    DeleteUserError, DeleteUserOutputBoundary, DeleteUserOutputError,
+};
+
+pub struct DeleteUserPresenter {
+    pub(crate) output: Option<String>,
+}
+
+impl DeleteUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+    pub(crate) fn success(&self, _output: String) -> Result<StatusCode, Problem> {
+        Ok(StatusCode::NO_CONTENT)
+    }
+    pub(crate) fn failure(&self, error: DeleteUserError) -> Problem {
+        match &error {
+            DeleteUserError::CommandError(error) => Problem::from_command_error(error),
+            DeleteUserError::TransactionError(error) => Problem::from_transaction_error(error),
+            DeleteUserError::OutputError(_) => Problem::internal(&error),
+        }
+    }
+}
+
+impl DeleteUserOutputBoundary for DeleteUserPresenter {
+    fn execute(&mut self, output: String) -> Result<(), DeleteUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
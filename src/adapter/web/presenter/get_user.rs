@@ -0,0 +1,33 @@
+use axum::Json;
+
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::get_user::{GetUserError, GetUserOutputBoundary, GetUserOutputError};
+
+pub struct GetUserPresenter {
+    pub(crate) output: Option<UserReadModel>,
+}
+
+impl GetUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+    pub(crate) fn success(&self, output: UserReadModel) -> Result<Json<UserWebOutput>, Problem> {
+        Ok(Json(UserWebOutput::from(output)))
+    }
+    pub(crate) fn failure(&self, error: GetUserError) -> Problem {
+        match &error {
+            GetUserError::QueryError(error) => Problem::from_query_error(error),
+            GetUserError::TransactionError(error) => Problem::from_transaction_error(error),
+            GetUserError::OutputError(_) => Problem::internal(&error),
+        }
+    }
+}
+
+impl GetUserOutputBoundary for GetUserPresenter {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), GetUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use axum::Json;
+
+use crate::adapter::web::dto::list_users_web_output::ListUsersWebOutput;
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::entity::user::value::FieldViolation;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::list_users::{
+    ListUsersError, ListUsersInput, ListUsersOutputBoundary, ListUsersOutputError,
+};
+
+pub struct ListUsersPresenter {
+    pub(crate) output: Option<Vec<UserReadModel>>,
+}
+
+impl ListUsersPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+    pub(crate) fn success(
+        &self,
+        input: ListUsersInput,
+        output: Vec<UserReadModel>,
+    ) -> Result<Json<ListUsersWebOutput>, Problem> {
+        // 1ページ分埋まっていれば次のページがあるものとみなす
+        let next_offset = (output.len() as u32 == input.limit)
+            .then(|| input.offset.checked_add(input.limit))
+            .flatten();
+        Ok(Json(ListUsersWebOutput {
+            items: output.into_iter().map(UserWebOutput::from).collect(),
+            limit: input.limit,
+            offset: input.offset,
+            next_offset,
+        }))
+    }
+    pub(crate) fn failure(&self, error: ListUsersError) -> Problem {
+        match &error {
+            ListUsersError::InvalidLimit(_) => {
+                Problem::validation(&[FieldViolation::new("limit", error.to_string())])
+            }
+            ListUsersError::InvalidOffset(_) => {
+                Problem::validation(&[FieldViolation::new("offset", error.to_string())])
+            }
+            ListUsersError::QueryError(error) => Problem::from_query_error(error),
+            ListUsersError::TransactionError(error) => Problem::from_transaction_error(error),
+            ListUsersError::OutputError(_) => Problem::internal(&error),
+        }
+    }
+}
+
+impl ListUsersOutputBoundary for ListUsersPresenter {
+    fn execute(&mut self, output: Vec<UserReadModel>) -> Result<(), ListUsersOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
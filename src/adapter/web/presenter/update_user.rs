@@ -0,0 +1,36 @@
+use axum::Json;
+
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::problem::Problem;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::port::update_user::{
+    UpdateUserError, UpdateUserOutputBoundary, UpdateUserOutputError,
+};
+
+pub struct UpdateUserPresenter {
+    pub(crate) output: Option<UserReadModel>,
+}
+
+impl UpdateUserPresenter {
+    pub fn new() -> Self {
+        Self { output: None }
+    }
+    pub(crate) fn success(&self, output: UserReadModel) -> Result<Json<UserWebOutput>, Problem> {
+        Ok(Json(UserWebOutput::from(output)))
+    }
+    pub(crate) fn failure(&self, error: UpdateUserError) -> Problem {
+        match &error {
+            UpdateUserError::ValidationError(error) => Problem::validation(error.violations()),
+            UpdateUserError::CommandError(error) => Problem::from_command_error(error),
+            UpdateUserError::TransactionError(error) => Problem::from_transaction_error(error),
+            UpdateUserError::OutputError(_) => Problem::internal(&error),
+        }
+    }
+}
+
+impl UpdateUserOutputBoundary for UpdateUserPresenter {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), UpdateUserOutputError> {
+        self.output = Some(output);
+        Ok(())
+    }
+}
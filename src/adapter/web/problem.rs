@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+
+use axum::http::header::{CONTENT_TYPE, RETRY_AFTER};
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::core::domain::command::CommandError;
+use crate::core::domain::entity::user::value::FieldViolation;
+use crate::core::domain::query::QueryError;
+use crate::core::domain::transaction::TransactionError;
+use crate::core::domain::transaction_manager::TransactionManagerError;
+use crate::core::domain::transaction_operation::TransactionOperationError;
+
+const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+// 競合やシャットダウン中の拒否は、少し待って再送すれば成功しうる
+const RETRY_AFTER_SECS: u32 = 1;
+
+#[derive(Debug, Serialize)]
+pub struct ProblemFieldError {
+    pub field: String,
+    pub message: String,
+}
+
+// RFC 7807 のエラーレスポンス。type は about:blank とし、title にはステータスの説明を入れる
+#[derive(Debug, Serialize)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    problem_type: &'static str,
+    title: &'static str,
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<ProblemFieldError>,
+    // 500 の原因をサーバーのログから探すための識別子
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_id: Option<String>,
+    #[serde(skip)]
+    retry_after: Option<u32>,
+}
+
+impl Problem {
+    pub fn new(status: StatusCode, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: "about:blank",
+            title: status.canonical_reason().unwrap_or_default(),
+            status: status.as_u16(),
+            detail: Some(detail.into()),
+            errors: Vec::new(),
+            error_id: None,
+            retry_after: None,
+        }
+    }
+
+    pub fn validation(violations: &[FieldViolation]) -> Self {
+        Self::invalid_fields(
+            violations
+                .iter()
+                .map(|violation| ProblemFieldError {
+                    field: violation.field.to_string(),
+                    message: violation.message.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    // ドメインの検証より前 (リクエストの解釈時) に見つかった不正な項目にも使う
+    pub fn invalid_fields(errors: Vec<ProblemFieldError>) -> Self {
+        Self {
+            errors,
+            ..Self::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "The request contains invalid fields",
+            )
+        }
+    }
+
+    // 内部の詳細 (SQLなど) はレスポンスに含めず、識別子と一緒にログに残す
+    pub fn internal(error: &impl Debug) -> Self {
+        let error_id = Uuid::now_v7().to_string();
+        error!(error_id = %error_id, error = ?error, "Unhandled error");
+        Self {
+            error_id: Some(error_id),
+            ..Self::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "An unexpected error occurred",
+            )
+        }
+    }
+
+    pub fn with_retry_after(mut self, secs: u32) -> Self {
+        self.retry_after = Some(secs);
+        self
+    }
+
+    pub fn from_command_error(error: &CommandError) -> Self {
+        match error {
+            CommandError::NotFound { .. } => Self::new(StatusCode::NOT_FOUND, error.to_string()),
+            CommandError::AlreadyExists { .. } => {
+                Self::new(StatusCode::CONFLICT, error.to_string())
+            }
+            CommandError::ConcurrencyError { .. } => {
+                Self::new(StatusCode::CONFLICT, error.to_string())
+                    .with_retry_after(RETRY_AFTER_SECS)
+            }
+            CommandError::ValidationError { .. } | CommandError::IdempotencyKeyReused { .. } => {
+                Self::new(StatusCode::UNPROCESSABLE_ENTITY, error.to_string())
+            }
+            CommandError::DatabaseError(_) => Self::internal(error),
+        }
+    }
+
+    pub fn from_query_error(error: &QueryError) -> Self {
+        match error {
+            QueryError::NotFound { .. } => Self::new(StatusCode::NOT_FOUND, error.to_string()),
+            QueryError::DatabaseError(_) | QueryError::MalformedRow(_) => Self::internal(error),
+        }
+    }
+
+    pub fn from_transaction_error(error: &TransactionManagerError) -> Self {
+        let source = match error {
+            TransactionManagerError::OperationError { source, .. } => match source {
+                TransactionOperationError::CommandError(error) => {
+                    return Self::from_command_error(error)
+                }
+                TransactionOperationError::QueryError(error) => {
+                    return Self::from_query_error(error)
+                }
+                TransactionOperationError::TransactionError(source) => Some(source),
+                TransactionOperationError::IdGeneratorError(_) => None,
+            },
+            TransactionManagerError::TransactionError { source, .. } => Some(source),
+            TransactionManagerError::BeginError(_) => None,
+        };
+        match source {
+            // 接続を取得できない、またはシャットダウンで作業単位が中断された
+            Some(TransactionError::ConnectionError(_) | TransactionError::Cancelled(_)) => {
+                Self::new(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "The service is temporarily unavailable",
+                )
+                .with_retry_after(RETRY_AFTER_SECS)
+            }
+            _ => Self::internal(error),
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let retry_after = self.retry_after;
+        let mut response = (status, Json(self)).into_response();
+        let headers = response.headers_mut();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_CONTENT_TYPE));
+        if let Some(secs) = retry_after {
+            headers.insert(RETRY_AFTER, secs.into());
+        }
+        response
+    }
+}
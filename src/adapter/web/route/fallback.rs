@@ -0,0 +1,18 @@
+use axum::http::{Method, StatusCode, Uri};
+
+use crate::adapter::web::problem::Problem;
+
+// ルートが無いリクエストにも他のエラーと同じ problem+json を返す
+pub async fn not_found(uri: Uri) -> Problem {
+    Problem::new(
+        StatusCode::NOT_FOUND,
+        format!("No route for {}", uri.path()),
+    )
+}
+
+pub async fn method_not_allowed(method: Method, uri: Uri) -> Problem {
+    Problem::new(
+        StatusCode::METHOD_NOT_ALLOWED,
+        format!("{} is not allowed for {}", method, uri.path()),
+    )
+}
@@ -0,0 +1,22 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+
+use crate::adapter::health::{HealthReport, HealthStatus};
+use crate::adapter::web::app_state::AppState;
+
+// プロセスが応答できることだけを返し、依存先は確認しない
+pub async fn healthz() -> Json<HealthReport> {
+    Json(HealthReport::up())
+}
+
+// データベースに到達できない場合は 503 を返し、トラフィックを外してもらう
+pub async fn readyz(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthReport>) {
+    let report = state.readiness.run().await;
+    let status = match report.status {
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+        HealthStatus::Ok | HealthStatus::Degraded => StatusCode::OK,
+    };
+    (status, Json(report))
+}
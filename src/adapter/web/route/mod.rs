@@ -1 +1,4 @@
+pub mod fallback;
+pub mod health;
+pub mod metrics;
 pub mod users;
@@ -5,12 +5,70 @@ use std::sync::Arc;
 
 use crate::adapter::web::app_state::AppState;
 use crate::adapter::web::dto::create_user_web_input::CreateUserWebInput;
+use crate::adapter::web::dto::list_users_web_output::ListUsersWebOutput;
+use crate::adapter::web::dto::list_users_web_query::ListUsersWebQuery;
+use crate::adapter::web::dto::update_user_web_input::UpdateUserWebInput;
+use crate::adapter::web::dto::user_web_output::UserWebOutput;
+use crate::adapter::web::extractor::admin::Admin;
+use crate::adapter::web::extractor::idempotency_key::OptionalIdempotencyKey;
+use crate::adapter::web::extractor::json::Json as JsonBody;
+use crate::adapter::web::extractor::path::Path;
+use crate::adapter::web::extractor::query::Query;
+use crate::adapter::web::extractor::tenant::Tenant;
+use crate::adapter::web::handler::users::delete::DeleteUserHandler;
+use crate::adapter::web::handler::users::get::GetUserHandler;
+use crate::adapter::web::handler::users::list::ListUsersHandler;
+use crate::adapter::web::handler::users::patch::UpdateUserHandler;
 use crate::adapter::web::handler::users::post::UserHandler;
+use crate::adapter::web::presenter::create_user::CreatedUser;
+use crate::adapter::web::problem::Problem;
 
 pub async fn post(
     State(state): State<Arc<AppState>>,
-    Json(user): Json<CreateUserWebInput>,
-) -> Result<StatusCode, (StatusCode, String)> {
+    Tenant(tenant_id): Tenant,
+    Admin(is_admin): Admin,
+    OptionalIdempotencyKey(idempotency_key): OptionalIdempotencyKey,
+    JsonBody(user): JsonBody<CreateUserWebInput>,
+) -> Result<CreatedUser, Problem> {
     let handler = UserHandler::new(state.user_create_use_case.clone());
-    handler.create_user(user).await
+    handler
+        .create_user(tenant_id, is_admin, idempotency_key, user)
+        .await
+}
+
+pub async fn get(
+    State(state): State<Arc<AppState>>,
+    Tenant(tenant_id): Tenant,
+    Path(id): Path<String>,
+) -> Result<Json<UserWebOutput>, Problem> {
+    let handler = GetUserHandler::new(state.user_get_use_case.clone());
+    handler.get_user(tenant_id, id).await
+}
+
+pub async fn list(
+    State(state): State<Arc<AppState>>,
+    Tenant(tenant_id): Tenant,
+    Query(query): Query<ListUsersWebQuery>,
+) -> Result<Json<ListUsersWebOutput>, Problem> {
+    let handler = ListUsersHandler::new(state.user_list_use_case.clone());
+    handler.list_users(tenant_id, query).await
+}
+
+pub async fn patch(
+    State(state): State<Arc<AppState>>,
+    Tenant(tenant_id): Tenant,
+    Path(id): Path<String>,
+    JsonBody(user): JsonBody<UpdateUserWebInput>,
+) -> Result<Json<UserWebOutput>, Problem> {
+    let handler = UpdateUserHandler::new(state.user_update_use_case.clone());
+    handler.update_user(tenant_id, id, user).await
+}
+
+pub async fn delete(
+    State(state): State<Arc<AppState>>,
+    Tenant(tenant_id): Tenant,
+    Path(id): Path<String>,
+) -> Result<StatusCode, Problem> {
+    let handler = DeleteUserHandler::new(state.user_delete_use_case.clone());
+    handler.delete_user(tenant_id, id).await
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::core::domain::entity::user::value::UserId;
+
 #[derive(Debug, Error)]
 pub enum CommandError {
     #[error("Entity already exists: {entity_type} - {details}")]
@@ -26,18 +28,23 @@ pub enum CommandError {
     ValidationError {
         details: String,
     },
+
+    #[error("Idempotency key was reused with a different request: {key}")]
+    IdempotencyKeyReused {
+        key: String,
+    },
 }
 
 impl CommandError {
     // User向けのヘルパーメソッド
-    pub fn user_not_found(id: i32) -> Self {
+    pub fn user_not_found(id: &str) -> Self {
         CommandError::NotFound {
             entity_type: "User".to_string(),
             details: format!("id: {}", id),
         }
     }
 
-    pub fn user_already_exists(id: i32) -> Self {
+    pub fn user_already_exists(id: UserId) -> Self {
         CommandError::AlreadyExists {
             entity_type: "User".to_string(),
             details: format!("id: {}", id),
@@ -1,15 +1,35 @@
+#[allow(clippy::module_inception)]
 pub mod user;
+pub mod value;
 
 use crate::core::domain::transaction::TransactionWrapper;
 use async_trait::async_trait;
-use thiserror::Error;
 use crate::core::domain::command::CommandError;
+use crate::core::domain::entity::user::value::{Email, UserId, UserName};
 
 #[derive(Debug, Clone)]
 pub struct User {
-    pub id: i32,
-    pub name: String,
-    pub email: String,
+    pub id: UserId,
+    pub name: UserName,
+    pub email: Email,
+}
+
+// 作成を要求されたユーザー。id が None の場合は保存前に IdGenerator で生成する
+#[derive(Debug, Clone)]
+pub struct NewUser {
+    pub id: Option<UserId>,
+    pub name: UserName,
+    pub email: Email,
+}
+
+impl NewUser {
+    pub fn with_id(self, id: UserId) -> User {
+        User {
+            id,
+            name: self.name,
+            email: self.email,
+        }
+    }
 }
 
 #[async_trait]
@@ -19,5 +39,25 @@ pub trait UserCommand: Send + Sync {
         transaction: &mut Box<dyn TransactionWrapper>,
         user: User,
     ) -> Result<(), CommandError>;
+
+    async fn find_by_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<Option<User>, CommandError>;
+
+    // 該当するユーザーが存在しない場合は NotFound を返す
+    async fn update(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        user: User,
+    ) -> Result<(), CommandError>;
+
+    // 該当するユーザーが存在しない場合は NotFound を返す
+    async fn delete(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<(), CommandError>;
 }
 
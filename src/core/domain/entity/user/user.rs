@@ -1,25 +1,161 @@
 use thiserror::Error;
 
-use crate::core::domain::entity::user::User;
+use crate::core::domain::entity::user::value::{Email, FieldViolation, UserId, UserName};
+use crate::core::domain::entity::user::{NewUser, User};
 
 #[derive(Debug, Error)]
-pub enum CreateUserValidationError {}
+pub enum CreateUserValidationError {
+    // 最初の違反で止めず、すべての違反をまとめて返す
+    #[error("Invalid user: {}", format_violations(.0))]
+    InvalidFields(Vec<FieldViolation>),
+}
+
+impl CreateUserValidationError {
+    pub fn violations(&self) -> &[FieldViolation] {
+        match self {
+            CreateUserValidationError::InvalidFields(violations) => violations,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateUserValidationError {
+    #[error("Invalid user: {}", format_violations(.0))]
+    InvalidFields(Vec<FieldViolation>),
+}
+
+// 保存済みの行が不変条件を満たさない場合。リポジトリはデータベースの異常として扱う
+#[derive(Debug, Error)]
+pub enum RehydrateUserError {
+    #[error("Invalid stored user: {}", format_violations(.0))]
+    InvalidFields(Vec<FieldViolation>),
+}
+
+impl UpdateUserValidationError {
+    pub fn violations(&self) -> &[FieldViolation] {
+        match self {
+            UpdateUserValidationError::InvalidFields(violations) => violations,
+        }
+    }
+}
+
+fn format_violations(violations: &[FieldViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
 #[derive(Debug)]
 pub struct UnvalidatedCreateUserInput {
-    pub id: i32,
+    // 指定しなければ設定された採番方式で生成する
+    pub id: Option<String>,
     pub name: String,
     pub email: String,
 }
 
-impl TryFrom<UnvalidatedCreateUserInput> for User {
+impl TryFrom<UnvalidatedCreateUserInput> for NewUser {
     type Error = CreateUserValidationError;
 
     fn try_from(value: UnvalidatedCreateUserInput) -> Result<Self, Self::Error> {
-        Ok(User {
-            id: value.id,
-            name: value.name,
-            email: value.email,
-        })
+        let id = value.id.map(UserId::try_from).transpose();
+        let name = UserName::try_from(value.name);
+        let email = Email::try_from(value.email);
+        match (id, name, email) {
+            (Ok(id), Ok(name), Ok(email)) => Ok(NewUser { id, name, email }),
+            (id, name, email) => Err(CreateUserValidationError::InvalidFields(
+                [id.err(), name.err(), email.err()]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            )),
+        }
+    }
+}
+
+impl User {
+    // 保存済みの行から復元する。値オブジェクトは必ずここか TryFrom を通して作り、検証を省かない
+    pub fn rehydrate(id: String, name: String, email: String) -> Result<Self, RehydrateUserError> {
+        let id = UserId::try_from(id);
+        let name = UserName::try_from(name);
+        let email = Email::try_from(email);
+        match (id, name, email) {
+            (Ok(id), Ok(name), Ok(email)) => Ok(User { id, name, email }),
+            (id, name, email) => Err(RehydrateUserError::InvalidFields(
+                [id.err(), name.err(), email.err()]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(id: Option<&str>, name: &str, email: &str) -> UnvalidatedCreateUserInput {
+        UnvalidatedCreateUserInput {
+            id: id.map(str::to_string),
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    #[test]
+    fn valid_input_is_trimmed() {
+        let user = NewUser::try_from(input(None, " Alice ", " alice@example.com ")).unwrap();
+        assert!(user.id.is_none());
+        assert_eq!(user.name.as_str(), "Alice");
+        assert_eq!(user.email.as_str(), "alice@example.com");
+    }
+
+    #[test]
+    fn every_violation_is_reported() {
+        let error = NewUser::try_from(input(Some("a/b"), "", "not-an-email")).unwrap_err();
+        let fields: Vec<_> = error.violations().iter().map(|v| v.field).collect();
+        assert_eq!(fields, ["id", "name", "email"]);
+        assert_eq!(
+            error.to_string(),
+            "Invalid user: id: must contain only letters, digits and hyphens, \
+             name: must not be empty, email: must be a valid email address"
+        );
+    }
+
+    #[test]
+    fn stored_user_is_rehydrated_when_valid() {
+        let user = User::rehydrate(
+            "42".to_string(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+        )
+        .unwrap();
+        assert_eq!(user.id.as_str(), "42");
+        assert_eq!(user.name.as_str(), "Alice");
+        assert_eq!(user.email.as_str(), "alice@example.com");
+    }
+
+    #[test]
+    fn stored_user_violating_invariants_is_rejected() {
+        let error =
+            User::rehydrate("42".to_string(), "".to_string(), "alice@".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid stored user: name: must not be empty, email: must be a valid email address"
+        );
+    }
+
+    #[test]
+    fn single_violation_is_reported_alone() {
+        let error = NewUser::try_from(input(None, "Alice", "alice@")).unwrap_err();
+        assert_eq!(
+            error.violations(),
+            [FieldViolation::new(
+                "email",
+                "must be a valid email address"
+            )]
+        );
     }
 }
@@ -0,0 +1,257 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// users テーブルの列の長さに合わせる (IDはハイフン付きのUUIDが収まる長さ)
+pub const MAX_USER_ID_LENGTH: usize = 36;
+pub const MAX_NAME_LENGTH: usize = 100;
+pub const MAX_EMAIL_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl FieldViolation {
+    pub fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+// 採番方式によって連番・UUID・ULIDなどになるため、形式は問わず文字列として扱う
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UserId(String);
+
+impl UserId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for UserId {
+    type Error = FieldViolation;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(FieldViolation::new("id", "must not be empty"));
+        }
+        if value.len() > MAX_USER_ID_LENGTH {
+            return Err(FieldViolation::new(
+                "id",
+                format!("must be at most {} characters", MAX_USER_ID_LENGTH),
+            ));
+        }
+        if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(FieldViolation::new(
+                "id",
+                "must contain only letters, digits and hyphens",
+            ));
+        }
+        Ok(UserId(value))
+    }
+}
+
+impl From<UserId> for String {
+    fn from(value: UserId) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UserName(String);
+
+impl UserName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for UserName {
+    type Error = FieldViolation;
+
+    // 前後の空白は取り除いてから検証する
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(FieldViolation::new("name", "must not be empty"));
+        }
+        if value.chars().count() > MAX_NAME_LENGTH {
+            return Err(FieldViolation::new(
+                "name",
+                format!("must be at most {} characters", MAX_NAME_LENGTH),
+            ));
+        }
+        Ok(UserName(value))
+    }
+}
+
+impl From<UserName> for String {
+    fn from(value: UserName) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for UserName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Email(String);
+
+impl Email {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = FieldViolation;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.chars().count() > MAX_EMAIL_LENGTH {
+            return Err(FieldViolation::new(
+                "email",
+                format!("must be at most {} characters", MAX_EMAIL_LENGTH),
+            ));
+        }
+        if !is_valid_email(&value) {
+            return Err(FieldViolation::new(
+                "email",
+                "must be a valid email address",
+            ));
+        }
+        Ok(Email(value))
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// RFC 5322 の全体ではなく、local@domain.tld の形になっているかだけを確認する
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    if local.is_empty() || domain.contains('@') || email.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_is_trimmed() {
+        let name = UserName::try_from("  Alice  ".to_string()).unwrap();
+        assert_eq!(name.as_str(), "Alice");
+    }
+
+    #[test]
+    fn blank_name_is_rejected() {
+        let violation = UserName::try_from("   ".to_string()).unwrap_err();
+        assert_eq!(violation.field, "name");
+    }
+
+    #[test]
+    fn name_length_is_counted_in_characters() {
+        assert!(UserName::try_from("あ".repeat(MAX_NAME_LENGTH)).is_ok());
+        assert!(UserName::try_from("a".repeat(MAX_NAME_LENGTH + 1)).is_err());
+        // 前後の空白は長さに含めない
+        assert!(UserName::try_from(format!(" {} ", "a".repeat(MAX_NAME_LENGTH))).is_ok());
+    }
+
+    #[test]
+    fn email_length_boundary() {
+        let domain = "@example.com";
+        let local = |length: usize| "a".repeat(length - domain.len());
+        assert!(Email::try_from(format!("{}{}", local(MAX_EMAIL_LENGTH), domain)).is_ok());
+        assert!(Email::try_from(format!("{}{}", local(MAX_EMAIL_LENGTH + 1), domain)).is_err());
+    }
+
+    #[test]
+    fn valid_emails_are_accepted_and_trimmed() {
+        let email = Email::try_from(" alice@example.com ".to_string()).unwrap();
+        assert_eq!(email.as_str(), "alice@example.com");
+        for email in ["a@b.co", "first.last+tag@sub.example-mail.com"] {
+            assert!(Email::try_from(email.to_string()).is_ok(), "{}", email);
+        }
+    }
+
+    #[test]
+    fn malformed_emails_are_rejected() {
+        for email in [
+            "",
+            "alice",
+            "@example.com",
+            "alice@",
+            "alice@example",
+            "alice@@example.com",
+            "alice@exa mple.com",
+            "alice@example..com",
+            "alice@-example.com",
+            "alice@example-.com",
+            "alice@exam_ple.com",
+        ] {
+            let violation = Email::try_from(email.to_string()).unwrap_err();
+            assert_eq!(violation.field, "email", "{}", email);
+        }
+    }
+
+    #[test]
+    fn user_id_rules() {
+        assert_eq!(
+            UserId::try_from(" 0192a-b ".to_string()).unwrap().as_str(),
+            "0192a-b"
+        );
+        assert!(UserId::try_from("a".repeat(MAX_USER_ID_LENGTH)).is_ok());
+        for id in [
+            "",
+            "a".repeat(MAX_USER_ID_LENGTH + 1).as_str(),
+            "a/b",
+            "a b",
+        ] {
+            assert!(UserId::try_from(id.to_string()).is_err(), "{}", id);
+        }
+    }
+}
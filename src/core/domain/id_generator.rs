@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::core::domain::transaction::TransactionWrapper;
+
+#[derive(Debug, Error)]
+pub enum IdGeneratorError {
+    #[error("Failed to generate id: {0}")]
+    DatabaseError(String),
+
+    #[error("Generated id is invalid: {0}")]
+    InvalidId(String),
+}
+
+// 集約を保存する前にIDを確定させ、作業単位の中でフラッシュ前から識別子を扱えるようにする
+#[async_trait]
+pub trait IdGenerator: Send + Sync {
+    // データベースのシーケンスを使う実装もあるため、作業単位のトランザクションを受け取る
+    async fn next_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<String, IdGeneratorError>;
+
+    // クライアントが指定したIDを採番済みとして扱い、後で同じIDを払い出さないようにする
+    async fn reserve(
+        &self,
+        _transaction: &mut Box<dyn TransactionWrapper>,
+        _id: &str,
+    ) -> Result<(), IdGeneratorError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::core::domain::command::CommandError;
+use crate::core::domain::transaction::TransactionWrapper;
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+#[derive(Debug, Error)]
+#[error("Invalid idempotency key: {0}")]
+pub struct IdempotencyKeyError(String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for IdempotencyKey {
+    type Error = IdempotencyKeyError;
+
+    // idempotency_keys.key は varchar(255)
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty()
+            || value.len() > MAX_IDEMPOTENCY_KEY_LENGTH
+            || !value.chars().all(|c| c.is_ascii_graphic())
+        {
+            return Err(IdempotencyKeyError(value));
+        }
+        Ok(IdempotencyKey(value))
+    }
+}
+
+// 同じキーで異なるリクエストが送られたことを検出するためのハッシュ
+pub fn request_hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[derive(Debug, Clone)]
+pub struct IdempotencyRecord {
+    pub key: IdempotencyKey,
+    pub request_hash: String,
+    // 最初のリクエストで出力境界に渡された値 (JSON)
+    pub response: String,
+}
+
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    // 同じキーの並行リクエストをトランザクション終了まで待たせる
+    async fn lock(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        key: &IdempotencyKey,
+    ) -> Result<(), CommandError>;
+
+    async fn find(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        key: &IdempotencyKey,
+    ) -> Result<Option<IdempotencyRecord>, CommandError>;
+
+    async fn insert(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        record: IdempotencyRecord,
+    ) -> Result<(), CommandError>;
+}
@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use crate::core::domain::transaction::ToSql;
+
+const REDACTED: &str = "[REDACTED]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactionPolicy {
+    None,
+    All,
+    // 指定した列に対応するパラメータだけを伏せる
+    Columns(Vec<String>),
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy::Columns(vec!["email".to_string()])
+    }
+}
+
+impl RedactionPolicy {
+    pub fn redact(&self, query: &str, params: &[Box<dyn ToSql>]) -> Vec<String> {
+        let columns = match self {
+            RedactionPolicy::None => HashMap::new(),
+            RedactionPolicy::All => return vec![REDACTED.to_string(); params.len()],
+            RedactionPolicy::Columns(_) => parameter_columns(query),
+        };
+
+        params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| match columns.get(&(index + 1)) {
+                Some(column) if self.is_sensitive(column) => REDACTED.to_string(),
+                Some(_) => format!("{:?}", param),
+                // 列を特定できなかった値は、伏せるべき列かどうか分からないため伏せておく
+                None if matches!(self, RedactionPolicy::Columns(_)) => REDACTED.to_string(),
+                None => format!("{:?}", param),
+            })
+            .collect()
+    }
+
+    fn is_sensitive(&self, column: &str) -> bool {
+        match self {
+            RedactionPolicy::None => false,
+            RedactionPolicy::All => true,
+            RedactionPolicy::Columns(columns) => {
+                columns.iter().any(|c| c.eq_ignore_ascii_case(column))
+            }
+        }
+    }
+}
+
+// $n がどの列に対応するかをクエリから推測する
+// INSERT INTO t (a, b) VALUES ($1, $2) と a = $1 の形に対応し、それ以外の $n は含めない
+fn parameter_columns(query: &str) -> HashMap<usize, String> {
+    let query = query.to_ascii_lowercase();
+    let mut columns = HashMap::new();
+
+    if let Some(insert) = query.find("insert into") {
+        let rest = &query[insert..];
+        if let (Some(names), Some(values)) = (
+            parenthesized(rest),
+            rest.find("values").and_then(|i| parenthesized(&rest[i..])),
+        ) {
+            for (name, value) in names.split(',').zip(values.split(',')) {
+                if let Some(position) = placeholder(value.trim()) {
+                    columns.insert(position, name.trim().to_string());
+                }
+            }
+        }
+    }
+
+    let bytes = query.as_bytes();
+    for (index, _) in query.match_indices('$') {
+        let digits: String = query[index + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        let Ok(position) = digits.parse::<usize>() else {
+            continue;
+        };
+
+        let mut end = index;
+        while end > 0 && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        let operator_end = end;
+        while end > 0 && b"=<>!".contains(&bytes[end - 1]) {
+            end -= 1;
+        }
+        if end == operator_end {
+            continue;
+        }
+        while end > 0 && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        let start = query[..end]
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        if start < end {
+            columns
+                .entry(position)
+                .or_insert_with(|| query[start..end].to_string());
+        }
+    }
+
+    columns
+}
+
+fn parenthesized(s: &str) -> Option<&str> {
+    let open = s.find('(')?;
+    let close = open + s[open..].find(')')?;
+    Some(&s[open + 1..close])
+}
+
+fn placeholder(s: &str) -> Option<usize> {
+    s.strip_prefix('$')?.parse().ok()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementOutcome {
+    Success,
+    Failure(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct StatementRecord {
+    pub query: String,
+    pub params: Vec<String>,
+    pub duration: Duration,
+    pub outcome: StatementOutcome,
+}
+
+impl fmt::Display for StatementRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match &self.outcome {
+            StatementOutcome::Success => "ok".to_string(),
+            StatementOutcome::Failure(error) => format!("failed: {}", error),
+        };
+        write!(
+            f,
+            "{} params=[{}] duration={:?} {}",
+            self.query,
+            self.params.join(", "),
+            self.duration,
+            outcome
+        )
+    }
+}
+
+// トランザクション内で実行された文の記録
+#[derive(Debug, Clone, Default)]
+pub struct StatementJournal {
+    records: Vec<StatementRecord>,
+}
+
+impl StatementJournal {
+    pub fn record(&mut self, record: StatementRecord) {
+        self.records.push(record);
+    }
+
+    pub fn records(&self) -> &[StatementRecord] {
+        &self.records
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+impl fmt::Display for StatementJournal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, record) in self.records.iter().enumerate() {
+            writeln!(f, "#{} {}", index + 1, record)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(values: &[&str]) -> Vec<Box<dyn ToSql>> {
+        values
+            .iter()
+            .map(|value| Box::new(value.to_string()) as Box<dyn ToSql>)
+            .collect()
+    }
+
+    fn redact(query: &str, values: &[&str]) -> Vec<String> {
+        RedactionPolicy::default().redact(query, &params(values))
+    }
+
+    #[test]
+    fn insert_redacts_the_email_column() {
+        let redacted = redact(
+            "INSERT INTO users (id, name, email) VALUES ($1, $2, $3)",
+            &["1", "alice", "alice@example.com"],
+        );
+        assert_eq!(redacted, ["\"1\"", "\"alice\"", REDACTED]);
+    }
+
+    #[test]
+    fn update_redacts_the_assigned_email() {
+        let redacted = redact(
+            "UPDATE users SET name = $2, email = $3 WHERE id = $1 RETURNING id",
+            &["1", "alice", "alice@example.com"],
+        );
+        assert_eq!(redacted, ["\"1\"", "\"alice\"", REDACTED]);
+    }
+
+    #[test]
+    fn where_clause_keeps_non_sensitive_columns() {
+        for query in [
+            "SELECT id, name, email FROM users WHERE id = $1",
+            "SELECT id, name, email, created_at FROM users WHERE id = $1",
+            "DELETE FROM users WHERE id = $1 RETURNING id",
+            "SELECT request_hash, response FROM idempotency_keys WHERE key = $1",
+        ] {
+            assert_eq!(redact(query, &["1"]), ["\"1\""], "{}", query);
+        }
+        assert_eq!(
+            redact(
+                "SELECT id FROM users WHERE email = $1",
+                &["alice@example.com"]
+            ),
+            [REDACTED]
+        );
+    }
+
+    #[test]
+    fn insert_into_idempotency_keys_keeps_its_columns() {
+        let redacted = redact(
+            "INSERT INTO idempotency_keys (key, request_hash, response) VALUES ($1, $2, $3)",
+            &["k", "h", "r"],
+        );
+        assert_eq!(redacted, ["\"k\"", "\"h\"", "\"r\""]);
+    }
+
+    #[test]
+    fn parameters_without_a_column_are_redacted() {
+        for (query, count) in [
+            ("SELECT nextval($1::regclass)::text AS id", 1),
+            ("SELECT id FROM users ORDER BY created_at, id LIMIT $1 OFFSET $2", 2),
+            ("SELECT pg_notify($1, $2)", 2),
+            (
+                "SELECT pg_advisory_xact_lock(hashtext(current_setting('app.tenant_id', true) || ':' || $1))",
+                1,
+            ),
+            ("SELECT id FROM users WHERE lower(email) = $1", 1),
+        ] {
+            let values = vec!["x"; count];
+            assert_eq!(redact(query, &values), vec![REDACTED; count], "{}", query);
+        }
+    }
+
+    #[test]
+    fn multi_row_values_redact_rows_that_were_not_parsed() {
+        let redacted = redact(
+            "INSERT INTO users (id, name, email) VALUES ($1, $2, $3), ($4, $5, $6)",
+            &["1", "a", "a@example.com", "2", "b", "b@example.com"],
+        );
+        assert_eq!(
+            redacted,
+            ["\"1\"", "\"a\"", REDACTED, REDACTED, REDACTED, REDACTED]
+        );
+    }
+
+    #[test]
+    fn policies_none_and_all() {
+        let query = "INSERT INTO users (id, email) VALUES ($1, $2)";
+        let values = params(&["1", "a@example.com"]);
+        assert_eq!(
+            RedactionPolicy::None.redact(query, &values),
+            ["\"1\"", "\"a@example.com\""]
+        );
+        assert_eq!(
+            RedactionPolicy::All.redact(query, &values),
+            [REDACTED, REDACTED]
+        );
+    }
+}
@@ -1,5 +1,12 @@
 pub mod entity;
+pub mod id_generator;
+pub mod idempotency;
+pub mod journal;
+pub mod notification;
+pub mod query;
 pub mod transaction;
+pub mod transaction_context;
 pub mod transaction_manager;
 pub mod transaction_operation;
 pub mod command;
+pub mod tenant;
@@ -0,0 +1,8 @@
+// pg_notify で送信されるチャネル名
+pub const USER_CREATED_CHANNEL: &str = "user_created";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub channel: String,
+    pub payload: String,
+}
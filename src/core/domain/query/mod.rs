@@ -0,0 +1,27 @@
+pub mod user;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum QueryError {
+    #[error("Entity not found: {entity_type} - {details}")]
+    NotFound {
+        entity_type: String,
+        details: String,
+    },
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Malformed row: {0}")]
+    MalformedRow(String),
+}
+
+impl QueryError {
+    pub fn user_not_found(id: &str) -> Self {
+        QueryError::NotFound {
+            entity_type: "User".to_string(),
+            details: format!("id: {}", id),
+        }
+    }
+}
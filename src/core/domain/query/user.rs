@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::domain::query::QueryError;
+use crate::core::domain::transaction::TransactionWrapper;
+
+// 読み取り専用の表現。集約の不変条件は持たず、表示に必要な項目をそのまま運ぶ
+// 冪等キーのレスポンスとしてもこの形のまま保存する
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserReadModel {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// 書き込みを伴わないため、読み取り専用トランザクション (レプリカ) でも実行できる
+#[async_trait]
+pub trait UserQuery: Send + Sync {
+    async fn find_by_id(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<Option<UserReadModel>, QueryError>;
+
+    // 作成順 (同時刻ならid順) に offset 件読み飛ばし、最大 limit 件を返す
+    async fn list(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<Vec<UserReadModel>, QueryError>;
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+const MAX_TENANT_ID_LENGTH: usize = 64;
+
+#[derive(Debug, Error)]
+pub enum TenantIdError {
+    #[error("Tenant id is missing")]
+    Missing,
+
+    #[error("Invalid tenant id: {0}")]
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TenantId(String);
+
+impl TenantId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = TenantIdError;
+
+    // 英数字・ハイフン・アンダースコアのみ許可する (users.tenant_id は varchar(64))
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(TenantIdError::Missing);
+        }
+        if value.len() > MAX_TENANT_ID_LENGTH
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(TenantIdError::Invalid(value));
+        }
+        Ok(TenantId(value))
+    }
+}
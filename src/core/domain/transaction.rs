@@ -1,6 +1,17 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use thiserror::Error;
 
+use crate::core::domain::journal::StatementJournal;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccessMode {
+    #[default]
+    ReadWrite,
+    ReadOnly,
+}
+
 #[async_trait]
 pub trait TransactionWrapper: Send + Sync {
     async fn execute(
@@ -8,6 +19,28 @@ pub trait TransactionWrapper: Send + Sync {
         query: &str,
         params: Vec<Box<dyn ToSql>>,
     ) -> Result<(), TransactionError>;
+
+    // 結果を全件メモリに載せず、トランザクションが生きている間だけ1行ずつ読み出す
+    fn fetch<'a>(
+        &'a mut self,
+        query: &'a str,
+        params: Vec<Box<dyn ToSql>>,
+    ) -> BoxStream<'a, Result<Row, TransactionError>>;
+
+    // 通知はコミット時にのみ配信され、ロールバックされた場合は破棄される
+    async fn notify(&mut self, channel: &str, payload: &str) -> Result<(), TransactionError> {
+        let params: Vec<Box<dyn ToSql>> = vec![
+            Box::new(channel.to_string()) as Box<dyn ToSql>,
+            Box::new(payload.to_string()) as Box<dyn ToSql>,
+        ];
+        self.execute("SELECT pg_notify($1, $2)", params).await
+    }
+
+    // これまでに実行された文の記録を取り出す
+    fn take_journal(&mut self) -> StatementJournal {
+        StatementJournal::default()
+    }
+
     async fn rollback(self: Box<Self>) -> Result<(), TransactionError>;
     async fn commit(self: Box<Self>) -> Result<(), TransactionError>;
 }
@@ -33,6 +66,56 @@ impl ToSql for String {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Null,
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Timestamp(DateTime<Utc>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Row {
+    columns: Vec<(String, SqlValue)>,
+}
+
+impl Row {
+    pub fn new(columns: Vec<(String, SqlValue)>) -> Self {
+        Self { columns }
+    }
+
+    pub fn get(&self, column: &str) -> Option<&SqlValue> {
+        self.columns
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_i32(&self, column: &str) -> Option<i32> {
+        match self.get(column) {
+            Some(SqlValue::I32(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn get_string(&self, column: &str) -> Option<String> {
+        match self.get(column) {
+            Some(SqlValue::String(value)) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_timestamp(&self, column: &str) -> Option<DateTime<Utc>> {
+        match self.get(column) {
+            Some(SqlValue::Timestamp(value)) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum TransactionError {
     #[error("Failed to execute query: {0}")]
@@ -45,4 +128,9 @@ pub enum TransactionError {
     ConnectionError(String),
     #[error("Parameter binding error: {0}")]
     BindError(String),
+    #[error("Failed to decode row: {0}")]
+    DecodeError(String),
+    // シャットダウンなどで作業単位が中断された
+    #[error("Transaction cancelled: {0}")]
+    Cancelled(String),
 }
@@ -0,0 +1,42 @@
+use std::collections::BTreeMap;
+
+// トランザクション内でのみ有効なセッション変数 (SET LOCAL 相当)
+// RLSポリシーやトリガーから current_setting('app.tenant_id') などで参照できる
+pub const TENANT_ID_KEY: &str = "app.tenant_id";
+pub const USER_ID_KEY: &str = "app.user_id";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionContext {
+    settings: BTreeMap<String, String>,
+}
+
+impl TransactionContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.settings.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_tenant_id(self, tenant_id: impl Into<String>) -> Self {
+        self.with(TENANT_ID_KEY, tenant_id)
+    }
+
+    pub fn with_user_id(self, user_id: impl Into<String>) -> Self {
+        self.with(USER_ID_KEY, user_id)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.settings.get(key).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.settings.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
@@ -1,7 +1,11 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
+use crate::core::domain::command::CommandError;
+use crate::core::domain::journal::StatementJournal;
+use crate::core::domain::query::QueryError;
 use crate::core::domain::transaction::TransactionError;
+use crate::core::domain::transaction_context::TransactionContext;
 use crate::core::domain::transaction_operation::{
     BoxedTransactionOperation, TransactionOperationError,
 };
@@ -11,6 +15,16 @@ pub trait TransactionManager: Send + Sync {
     async fn execute(
         &self,
         operation: Box<dyn BoxedTransactionOperation>,
+    ) -> Result<(), TransactionManagerError> {
+        self.execute_with_context(TransactionContext::default(), operation)
+            .await
+    }
+
+    // contextはBEGIN直後にトランザクションローカルな設定として適用される
+    async fn execute_with_context(
+        &self,
+        context: TransactionContext,
+        operation: Box<dyn BoxedTransactionOperation>,
     ) -> Result<(), TransactionManagerError>;
 }
 
@@ -19,9 +33,69 @@ pub enum TransactionManagerError {
     #[error("Failed to begin transaction: {0}")]
     BeginError(String),
 
-    #[error(transparent)]
-    OperationError(#[from] TransactionOperationError),
+    // journalにはロールバックまでに実行された文が記録されている
+    #[error("{source}")]
+    OperationError {
+        source: TransactionOperationError,
+        journal: StatementJournal,
+    },
+
+    #[error("{source}")]
+    TransactionError {
+        source: TransactionError,
+        journal: StatementJournal,
+    },
+}
+
+impl TransactionManagerError {
+    // 同時実行の競合による失敗は作業単位をやり直せば成功しうる
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            TransactionManagerError::OperationError {
+                source: TransactionOperationError::CommandError(
+                    CommandError::ConcurrencyError { .. }
+                ),
+                ..
+            }
+        )
+    }
+
+    // 作業単位の中でコマンドが失敗した場合、その原因を返す
+    pub fn command_error(&self) -> Option<&CommandError> {
+        match self {
+            TransactionManagerError::OperationError {
+                source: TransactionOperationError::CommandError(error),
+                ..
+            } => Some(error),
+            _ => None,
+        }
+    }
+
+    pub fn query_error(&self) -> Option<&QueryError> {
+        match self {
+            TransactionManagerError::OperationError {
+                source: TransactionOperationError::QueryError(error),
+                ..
+            } => Some(error),
+            _ => None,
+        }
+    }
+
+    pub fn journal(&self) -> Option<&StatementJournal> {
+        match self {
+            TransactionManagerError::BeginError(_) => None,
+            TransactionManagerError::OperationError { journal, .. }
+            | TransactionManagerError::TransactionError { journal, .. } => Some(journal),
+        }
+    }
+}
 
-    #[error(transparent)]
-    TransactionError(#[from] TransactionError),
+impl From<TransactionError> for TransactionManagerError {
+    fn from(source: TransactionError) -> Self {
+        TransactionManagerError::TransactionError {
+            source,
+            journal: StatementJournal::default(),
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use thiserror::Error;
 use crate::core::domain::command::CommandError;
-use crate::core::domain::transaction::{TransactionError, TransactionWrapper};
+use crate::core::domain::id_generator::IdGeneratorError;
+use crate::core::domain::query::QueryError;
+use crate::core::domain::transaction::{AccessMode, TransactionError, TransactionWrapper};
 
 #[derive(Debug, Error)]
 pub enum TransactionOperationError {
@@ -10,6 +12,12 @@ pub enum TransactionOperationError {
 
     #[error(transparent)]
     CommandError(#[from] CommandError), // または他のコマンドのエラー
+
+    #[error(transparent)]
+    QueryError(#[from] QueryError),
+
+    #[error(transparent)]
+    IdGeneratorError(#[from] IdGeneratorError),
 }
 
 #[async_trait]
@@ -18,4 +26,15 @@ pub trait BoxedTransactionOperation: Send + Sync {
         &self,
         transaction: &mut Box<dyn TransactionWrapper>,
     ) -> Result<(), TransactionOperationError>;
+
+    // メトリクスやトレースのラベルに使われる
+    fn operation_type(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    // 読み取り専用の操作はレプリカへルーティングされる
+    fn access_mode(&self) -> AccessMode {
+        AccessMode::ReadWrite
+    }
 }
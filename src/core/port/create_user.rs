@@ -4,13 +4,18 @@ use crate::core::domain::command::CommandError;
 use crate::core::domain::entity::user::user::{
     CreateUserValidationError, UnvalidatedCreateUserInput,
 };
+use crate::core::domain::idempotency::IdempotencyKey;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::domain::tenant::TenantId;
 use crate::core::domain::transaction_manager::TransactionManagerError;
 
 #[async_trait]
 pub trait CreateUserInputBoundary: Send + Sync {
     async fn execute(
         &self,
+        tenant_id: TenantId,
         input: UnvalidatedCreateUserInput,
+        idempotency_key: Option<IdempotencyKey>,
         output_boundary: &mut dyn CreateUserOutputBoundary,
     ) -> Result<(), CreateUserError>;
 }
@@ -31,7 +36,7 @@ pub enum CreateUserError {
 }
 
 pub trait CreateUserOutputBoundary: Send + Sync {
-    fn execute(&mut self, output: i32) -> Result<(), CreateUserOutputError>;
+    fn execute(&mut self, output: UserReadModel) -> Result<(), CreateUserOutputError>;
 }
 
 #[derive(Debug, Error)]
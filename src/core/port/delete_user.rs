@@ -0,0 +1,41 @@
+use crate::core::domain::command::CommandError;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction_manager::TransactionManagerError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[async_trait]
+pub trait DeleteUserInputBoundary: Send + Sync {
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        output_boundary: &mut dyn DeleteUserOutputBoundary,
+    ) -> Result<(), DeleteUserError>;
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteUserError {
+    #[error(transparent)]
+    CommandError(#[from] CommandError),
+
+    #[error(transparent)]
+    TransactionError(#[from] TransactionManagerError),
+
+    #[error("Failed to process output: {0}")]
+    OutputError(#[from] DeleteUserOutputError),
+}
+
+pub trait DeleteUserOutputBoundary: Send + Sync {
+    // 削除したユーザーのid
+    fn execute(&mut self, output: String) -> Result<(), DeleteUserOutputError>;
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteUserOutputError {
+    #[error("Failed to format response: {0}")]
+    FormatError(String),
+
+    #[error("Invalid output state: {0}")]
+    InvalidStateError(String),
+}
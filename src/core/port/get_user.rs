@@ -0,0 +1,41 @@
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::domain::query::QueryError;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction_manager::TransactionManagerError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[async_trait]
+pub trait GetUserInputBoundary: Send + Sync {
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        output_boundary: &mut dyn GetUserOutputBoundary,
+    ) -> Result<(), GetUserError>;
+}
+
+#[derive(Debug, Error)]
+pub enum GetUserError {
+    #[error(transparent)]
+    QueryError(#[from] QueryError),
+
+    #[error(transparent)]
+    TransactionError(#[from] TransactionManagerError),
+
+    #[error("Failed to process output: {0}")]
+    OutputError(#[from] GetUserOutputError),
+}
+
+pub trait GetUserOutputBoundary: Send + Sync {
+    fn execute(&mut self, output: UserReadModel) -> Result<(), GetUserOutputError>;
+}
+
+#[derive(Debug, Error)]
+pub enum GetUserOutputError {
+    #[error("Failed to format response: {0}")]
+    FormatError(String),
+
+    #[error("Invalid output state: {0}")]
+    InvalidStateError(String),
+}
@@ -0,0 +1,55 @@
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::domain::query::QueryError;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction_manager::TransactionManagerError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+pub const MAX_LIST_USERS_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ListUsersInput {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+#[async_trait]
+pub trait ListUsersInputBoundary: Send + Sync {
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        input: ListUsersInput,
+        output_boundary: &mut dyn ListUsersOutputBoundary,
+    ) -> Result<(), ListUsersError>;
+}
+
+#[derive(Debug, Error)]
+pub enum ListUsersError {
+    #[error("limit must be between 1 and {MAX_LIST_USERS_LIMIT}: {0}")]
+    InvalidLimit(u32),
+
+    #[error("offset is too large: {0}")]
+    InvalidOffset(u32),
+
+    #[error(transparent)]
+    QueryError(#[from] QueryError),
+
+    #[error(transparent)]
+    TransactionError(#[from] TransactionManagerError),
+
+    #[error("Failed to process output: {0}")]
+    OutputError(#[from] ListUsersOutputError),
+}
+
+pub trait ListUsersOutputBoundary: Send + Sync {
+    fn execute(&mut self, output: Vec<UserReadModel>) -> Result<(), ListUsersOutputError>;
+}
+
+#[derive(Debug, Error)]
+pub enum ListUsersOutputError {
+    #[error("Failed to format response: {0}")]
+    FormatError(String),
+
+    #[error("Invalid output state: {0}")]
+    InvalidStateError(String),
+}
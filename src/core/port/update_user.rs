@@ -0,0 +1,54 @@
+use crate::core::domain::command::CommandError;
+use crate::core::domain::entity::user::user::UpdateUserValidationError;
+use crate::core::domain::query::user::UserReadModel;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction_manager::TransactionManagerError;
+use async_trait::async_trait;
+use thiserror::Error;
+
+// 指定された項目だけを変更する
+#[derive(Debug, Clone, Default)]
+pub struct UpdateUserInput {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[async_trait]
+pub trait UpdateUserInputBoundary: Send + Sync {
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        input: UpdateUserInput,
+        output_boundary: &mut dyn UpdateUserOutputBoundary,
+    ) -> Result<(), UpdateUserError>;
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateUserError {
+    #[error(transparent)]
+    ValidationError(#[from] UpdateUserValidationError),
+
+    #[error(transparent)]
+    CommandError(#[from] CommandError),
+
+    #[error(transparent)]
+    TransactionError(#[from] TransactionManagerError),
+
+    #[error("Failed to process output: {0}")]
+    OutputError(#[from] UpdateUserOutputError),
+}
+
+pub trait UpdateUserOutputBoundary: Send + Sync {
+    // 変更後のユーザー
+    fn execute(&mut self, output: UserReadModel) -> Result<(), UpdateUserOutputError>;
+}
+
+#[derive(Debug, Error)]
+pub enum UpdateUserOutputError {
+    #[error("Failed to format response: {0}")]
+    FormatError(String),
+
+    #[error("Invalid output state: {0}")]
+    InvalidStateError(String),
+}
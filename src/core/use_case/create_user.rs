@@ -1,30 +1,83 @@
 use async_trait::async_trait;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
 
-use crate::core::domain::entity::user::user::UnvalidatedCreateUserInput;
-use crate::core::domain::entity::user::{User, UserCommand};
+use crate::core::domain::command::CommandError;
+use crate::core::domain::entity::user::user::{
+    CreateUserValidationError, UnvalidatedCreateUserInput,
+};
+use crate::core::domain::entity::user::value::{FieldViolation, UserId};
+use crate::core::domain::entity::user::{NewUser, UserCommand};
+use crate::core::domain::id_generator::{IdGenerator, IdGeneratorError};
+use crate::core::domain::idempotency::{
+    request_hash, IdempotencyKey, IdempotencyRecord, IdempotencyRepository,
+};
+use crate::core::domain::notification::USER_CREATED_CHANNEL;
+use crate::core::domain::query::user::{UserQuery, UserReadModel};
+use crate::core::domain::query::QueryError;
+use crate::core::domain::tenant::TenantId;
 use crate::core::domain::transaction::TransactionWrapper;
+use crate::core::domain::transaction_context::TransactionContext;
 use crate::core::domain::transaction_manager::TransactionManager;
 use crate::core::domain::transaction_operation::{
     BoxedTransactionOperation, TransactionOperationError,
 };
 
 use crate::core::port::create_user::{
-    CreateUserError, CreateUserInputBoundary, CreateUserOutputBoundary,
+    CreateUserError, CreateUserInputBoundary, CreateUserOutputBoundary, CreateUserOutputError,
 };
 
+pub struct IdempotentRequest {
+    key: IdempotencyKey,
+    request_hash: String,
+    repository: Arc<dyn IdempotencyRepository>,
+}
+
 pub struct InsertUserOperation {
-    user: User,
+    user: NewUser,
     user_repository: Arc<dyn UserCommand>,
+    id_generator: Arc<dyn IdGenerator>,
+    user_query: Arc<dyn UserQuery>,
+    idempotency: Option<IdempotentRequest>,
+    // 作成した (再送の場合は最初に作成された) ユーザーをユースケースに返す
+    created: Arc<Mutex<Option<UserReadModel>>>,
 }
 
 impl InsertUserOperation {
-    pub fn new(user: User, user_repository: Arc<dyn UserCommand>) -> Self {
+    pub fn new(
+        user: NewUser,
+        user_repository: Arc<dyn UserCommand>,
+        id_generator: Arc<dyn IdGenerator>,
+        user_query: Arc<dyn UserQuery>,
+        created: Arc<Mutex<Option<UserReadModel>>>,
+    ) -> Self {
         Self {
             user,
             user_repository,
+            id_generator,
+            user_query,
+            idempotency: None,
+            created,
         }
     }
+
+    pub fn with_idempotency(mut self, idempotency: IdempotentRequest) -> Self {
+        self.idempotency = Some(idempotency);
+        self
+    }
+}
+
+impl InsertUserOperation {
+    async fn find_created(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+        id: &str,
+    ) -> Result<UserReadModel, QueryError> {
+        self.user_query
+            .find_by_id(transaction, id)
+            .await?
+            .ok_or_else(|| QueryError::user_not_found(id))
+    }
 }
 
 // BoxedTransactionOperationの実装
@@ -34,45 +87,195 @@ impl BoxedTransactionOperation for InsertUserOperation {
         &self,
         transaction: &mut Box<dyn TransactionWrapper>,
     ) -> Result<(), TransactionOperationError> {
+        if let Some(idempotency) = &self.idempotency {
+            idempotency
+                .repository
+                .lock(transaction, &idempotency.key)
+                .await?;
+            if let Some(record) = idempotency
+                .repository
+                .find(transaction, &idempotency.key)
+                .await?
+            {
+                if record.request_hash != idempotency.request_hash {
+                    return Err(CommandError::IdempotencyKeyReused {
+                        key: idempotency.key.as_str().to_string(),
+                    }
+                    .into());
+                }
+                // 再送の場合は最初のリクエストへのレスポンスを返す
+                *self.created.lock().unwrap() = Some(stored_response(&record.response)?);
+                return Ok(());
+            }
+        }
+
+        // 保存する前にIDを確定させる
+        let id = match &self.user.id {
+            Some(id) => {
+                self.id_generator.reserve(transaction, id.as_str()).await?;
+                id.clone()
+            }
+            None => {
+                let id = self.id_generator.next_id(transaction).await?;
+                UserId::try_from(id)
+                    .map_err(|violation| IdGeneratorError::InvalidId(violation.to_string()))?
+            }
+        };
         self.user_repository
-            .insert(transaction, self.user.clone())
+            .insert(transaction, self.user.clone().with_id(id.clone()))
             .await
-            .map_err(|e| TransactionOperationError::CommandError(e))?;
+            .map_err(TransactionOperationError::CommandError)?;
+        // created_at などDBが決める項目も返すため、同じトランザクションで読み直す
+        let created = self.find_created(transaction, id.as_str()).await?;
+
+        // ユーザーと同じトランザクションで保存し、どちらか一方だけが残らないようにする
+        if let Some(idempotency) = &self.idempotency {
+            let response = serde_json::to_string(&created)
+                .map_err(|e| CommandError::DatabaseError(e.to_string()))?;
+            idempotency
+                .repository
+                .insert(
+                    transaction,
+                    IdempotencyRecord {
+                        key: idempotency.key.clone(),
+                        request_hash: idempotency.request_hash.clone(),
+                        response,
+                    },
+                )
+                .await?;
+        }
+
+        transaction
+            .notify(USER_CREATED_CHANNEL, &id.to_string())
+            .await?;
+        *self.created.lock().unwrap() = Some(created);
         Ok(())
     }
+
+    fn operation_type(&self) -> &'static str {
+        "insert_user"
+    }
 }
 
 pub struct CreateUserUseCase {
     repository: Arc<dyn UserCommand>,
+    id_generator: Arc<dyn IdGenerator>,
+    user_query: Arc<dyn UserQuery>,
+    idempotency_repository: Arc<dyn IdempotencyRepository>,
     transaction_manager: Arc<dyn TransactionManager>,
+    allow_client_ids: bool,
 }
 
 impl CreateUserUseCase {
     pub fn new(
         repository: Arc<dyn UserCommand>,
+        id_generator: Arc<dyn IdGenerator>,
+        user_query: Arc<dyn UserQuery>,
+        idempotency_repository: Arc<dyn IdempotencyRepository>,
         transaction_manager: Arc<dyn TransactionManager>,
     ) -> Self {
         Self {
             repository,
+            id_generator,
+            user_query,
+            idempotency_repository,
             transaction_manager,
+            allow_client_ids: false,
         }
     }
+
+    // 管理用途でクライアントがIDを指定することを許可する
+    pub fn with_client_ids(mut self, allow_client_ids: bool) -> Self {
+        self.allow_client_ids = allow_client_ids;
+        self
+    }
 }
 
 #[async_trait]
 impl CreateUserInputBoundary for CreateUserUseCase {
+    #[instrument(name = "create_user", skip_all, fields(tenant_id = %tenant_id.as_str()))]
     async fn execute(
         &self,
+        tenant_id: TenantId,
         input: UnvalidatedCreateUserInput,
+        idempotency_key: Option<IdempotencyKey>,
         output_boundary: &mut dyn CreateUserOutputBoundary,
     ) -> Result<(), CreateUserError> {
-        let user = User::try_from(input)?;
-        let id = user.id;
-        let operation = Box::new(InsertUserOperation::new(user, self.repository.clone()));
-        self.transaction_manager.execute(operation).await?;
+        let mut input = input;
+        let mut violations = Vec::new();
+        // 許可されていないIDは形式を検証せずに拒否する
+        if input.id.take_if(|_| !self.allow_client_ids).is_some() {
+            violations.push(FieldViolation::new(
+                "id",
+                "must not be supplied; ids are assigned by the server",
+            ));
+        }
+        let user = match NewUser::try_from(input) {
+            Ok(user) if violations.is_empty() => user,
+            Ok(_) => return Err(CreateUserValidationError::InvalidFields(violations).into()),
+            Err(error) => {
+                violations.extend_from_slice(error.violations());
+                return Err(CreateUserValidationError::InvalidFields(violations).into());
+            }
+        };
+
+        let created = Arc::new(Mutex::new(None));
+        let mut operation = InsertUserOperation::new(
+            user.clone(),
+            self.repository.clone(),
+            self.id_generator.clone(),
+            self.user_query.clone(),
+            created.clone(),
+        );
+        if let Some(key) = idempotency_key {
+            let id = user.id.map(|id| id.to_string()).unwrap_or_default();
+            operation = operation.with_idempotency(IdempotentRequest {
+                key,
+                request_hash: request_hash(&[&id, user.name.as_str(), user.email.as_str()]),
+                repository: self.idempotency_repository.clone(),
+            });
+        }
+        let operation = Box::new(operation);
+        // テナントはRLSポリシーが参照するトランザクションコンテキストとして渡す
+        let context = TransactionContext::new().with_tenant_id(tenant_id.as_str());
+        self.transaction_manager
+            .execute_with_context(context, operation)
+            .await?;
 
-        output_boundary.execute(id)?;
+        let user = created.lock().unwrap().take().ok_or_else(|| {
+            CreateUserOutputError::InvalidStateError("Created user was not recorded".to_string())
+        })?;
+        output_boundary.execute(user)?;
 
         Ok(())
     }
 }
+
+// 作成したユーザーを出力境界に渡した形のまま保存し、再送にはそれを返す
+fn stored_response(response: &str) -> Result<UserReadModel, CommandError> {
+    serde_json::from_str(response)
+        .map_err(|e| CommandError::DatabaseError(format!("Unexpected stored response: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn stored_user_is_replayed_as_is() {
+        let user = UserReadModel {
+            id: "01J9".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+        };
+        let response = serde_json::to_string(&user).unwrap();
+        assert_eq!(stored_response(&response).unwrap(), user);
+    }
+
+    #[test]
+    fn malformed_stored_response_is_an_error() {
+        assert!(stored_response("{\"id\":\"1\"}").is_err());
+    }
+}
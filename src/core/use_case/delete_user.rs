@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::core::domain::entity::user::UserCommand;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction::TransactionWrapper;
+use crate::core::domain::transaction_context::TransactionContext;
+use crate::core::domain::transaction_manager::TransactionManager;
+use crate::core::domain::transaction_operation::{
+    BoxedTransactionOperation, TransactionOperationError,
+};
+
+use crate::core::port::delete_user::{
+    DeleteUserError, DeleteUserInputBoundary, DeleteUserOutputBoundary,
+};
+
+pub struct DeleteUserOperation {
+    id: String,
+    user_repository: Arc<dyn UserCommand>,
+}
+
+#[async_trait]
+impl BoxedTransactionOperation for DeleteUserOperation {
+    async fn execute(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<(), TransactionOperationError> {
+        self.user_repository.delete(transaction, &self.id).await?;
+        Ok(())
+    }
+
+    fn operation_type(&self) -> &'static str {
+        "delete_user"
+    }
+}
+
+pub struct DeleteUserUseCase {
+    repository: Arc<dyn UserCommand>,
+    transaction_manager: Arc<dyn TransactionManager>,
+}
+
+impl DeleteUserUseCase {
+    pub fn new(
+        repository: Arc<dyn UserCommand>,
+        transaction_manager: Arc<dyn TransactionManager>,
+    ) -> Self {
+        Self {
+            repository,
+            transaction_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteUserInputBoundary for DeleteUserUseCase {
+    #[instrument(name = "delete_user", skip_all, fields(tenant_id = %tenant_id.as_str(), user_id = %id))]
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        output_boundary: &mut dyn DeleteUserOutputBoundary,
+    ) -> Result<(), DeleteUserError> {
+        let operation = Box::new(DeleteUserOperation {
+            id: id.clone(),
+            user_repository: self.repository.clone(),
+        });
+        let context = TransactionContext::new().with_tenant_id(tenant_id.as_str());
+        self.transaction_manager
+            .execute_with_context(context, operation)
+            .await?;
+
+        output_boundary.execute(id)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
+
+use crate::core::domain::query::user::{UserQuery, UserReadModel};
+use crate::core::domain::query::QueryError;
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction::{AccessMode, TransactionWrapper};
+use crate::core::domain::transaction_context::TransactionContext;
+use crate::core::domain::transaction_manager::TransactionManager;
+use crate::core::domain::transaction_operation::{
+    BoxedTransactionOperation, TransactionOperationError,
+};
+
+use crate::core::port::get_user::{GetUserError, GetUserInputBoundary, GetUserOutputBoundary};
+
+pub struct FindUserOperation {
+    id: String,
+    user_query: Arc<dyn UserQuery>,
+    // トランザクション内で読み出した結果をユースケースに返す
+    found: Arc<Mutex<Option<UserReadModel>>>,
+}
+
+#[async_trait]
+impl BoxedTransactionOperation for FindUserOperation {
+    async fn execute(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<(), TransactionOperationError> {
+        let user = self.user_query.find_by_id(transaction, &self.id).await?;
+        *self.found.lock().unwrap() = user;
+        Ok(())
+    }
+
+    fn operation_type(&self) -> &'static str {
+        "get_user"
+    }
+
+    fn access_mode(&self) -> AccessMode {
+        AccessMode::ReadOnly
+    }
+}
+
+pub struct GetUserUseCase {
+    user_query: Arc<dyn UserQuery>,
+    transaction_manager: Arc<dyn TransactionManager>,
+}
+
+impl GetUserUseCase {
+    pub fn new(
+        user_query: Arc<dyn UserQuery>,
+        transaction_manager: Arc<dyn TransactionManager>,
+    ) -> Self {
+        Self {
+            user_query,
+            transaction_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl GetUserInputBoundary for GetUserUseCase {
+    #[instrument(name = "get_user", skip_all, fields(tenant_id = %tenant_id.as_str(), user_id = %id))]
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        output_boundary: &mut dyn GetUserOutputBoundary,
+    ) -> Result<(), GetUserError> {
+        let found = Arc::new(Mutex::new(None));
+        let operation = Box::new(FindUserOperation {
+            id: id.clone(),
+            user_query: self.user_query.clone(),
+            found: found.clone(),
+        });
+        let context = TransactionContext::new().with_tenant_id(tenant_id.as_str());
+        self.transaction_manager
+            .execute_with_context(context, operation)
+            .await?;
+
+        let user = found
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| QueryError::user_not_found(&id))?;
+        output_boundary.execute(user)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
+
+use crate::core::domain::query::user::{UserQuery, UserReadModel};
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction::{AccessMode, TransactionWrapper};
+use crate::core::domain::transaction_context::TransactionContext;
+use crate::core::domain::transaction_manager::TransactionManager;
+use crate::core::domain::transaction_operation::{
+    BoxedTransactionOperation, TransactionOperationError,
+};
+
+use crate::core::port::list_users::{
+    ListUsersError, ListUsersInput, ListUsersInputBoundary, ListUsersOutputBoundary,
+    MAX_LIST_USERS_LIMIT,
+};
+
+pub struct ListUsersOperation {
+    limit: i32,
+    offset: i32,
+    user_query: Arc<dyn UserQuery>,
+    users: Arc<Mutex<Vec<UserReadModel>>>,
+}
+
+#[async_trait]
+impl BoxedTransactionOperation for ListUsersOperation {
+    async fn execute(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<(), TransactionOperationError> {
+        let users = self
+            .user_query
+            .list(transaction, self.limit, self.offset)
+            .await?;
+        *self.users.lock().unwrap() = users;
+        Ok(())
+    }
+
+    fn operation_type(&self) -> &'static str {
+        "list_users"
+    }
+
+    fn access_mode(&self) -> AccessMode {
+        AccessMode::ReadOnly
+    }
+}
+
+pub struct ListUsersUseCase {
+    user_query: Arc<dyn UserQuery>,
+    transaction_manager: Arc<dyn TransactionManager>,
+}
+
+impl ListUsersUseCase {
+    pub fn new(
+        user_query: Arc<dyn UserQuery>,
+        transaction_manager: Arc<dyn TransactionManager>,
+    ) -> Self {
+        Self {
+            user_query,
+            transaction_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl ListUsersInputBoundary for ListUsersUseCase {
+    #[instrument(name = "list_users", skip_all, fields(tenant_id = %tenant_id.as_str()))]
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        input: ListUsersInput,
+        output_boundary: &mut dyn ListUsersOutputBoundary,
+    ) -> Result<(), ListUsersError> {
+        if input.limit == 0 || input.limit > MAX_LIST_USERS_LIMIT {
+            return Err(ListUsersError::InvalidLimit(input.limit));
+        }
+        let offset =
+            i32::try_from(input.offset).map_err(|_| ListUsersError::InvalidOffset(input.offset))?;
+
+        let users = Arc::new(Mutex::new(Vec::new()));
+        let operation = Box::new(ListUsersOperation {
+            limit: input.limit as i32,
+            offset,
+            user_query: self.user_query.clone(),
+            users: users.clone(),
+        });
+        let context = TransactionContext::new().with_tenant_id(tenant_id.as_str());
+        self.transaction_manager
+            .execute_with_context(context, operation)
+            .await?;
+
+        let users = std::mem::take(&mut *users.lock().unwrap());
+        output_boundary.execute(users)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
+
+use crate::core::domain::command::CommandError;
+use crate::core::domain::entity::user::user::UpdateUserValidationError;
+use crate::core::domain::entity::user::value::{Email, UserName};
+use crate::core::domain::entity::user::UserCommand;
+use crate::core::domain::query::user::{UserQuery, UserReadModel};
+use crate::core::domain::tenant::TenantId;
+use crate::core::domain::transaction::TransactionWrapper;
+use crate::core::domain::transaction_context::TransactionContext;
+use crate::core::domain::transaction_manager::TransactionManager;
+use crate::core::domain::transaction_operation::{
+    BoxedTransactionOperation, TransactionOperationError,
+};
+
+use crate::core::port::update_user::{
+    UpdateUserError, UpdateUserInput, UpdateUserInputBoundary, UpdateUserOutputBoundary,
+    UpdateUserOutputError,
+};
+
+pub struct UpdateUserOperation {
+    id: String,
+    name: Option<UserName>,
+    email: Option<Email>,
+    user_repository: Arc<dyn UserCommand>,
+    user_query: Arc<dyn UserQuery>,
+    updated: Arc<Mutex<Option<UserReadModel>>>,
+}
+
+#[async_trait]
+impl BoxedTransactionOperation for UpdateUserOperation {
+    async fn execute(
+        &self,
+        transaction: &mut Box<dyn TransactionWrapper>,
+    ) -> Result<(), TransactionOperationError> {
+        // 読み出しと更新を同じトランザクションで行い、指定されなかった項目は現在の値を残す
+        let mut user = self
+            .user_repository
+            .find_by_id(transaction, &self.id)
+            .await?
+            .ok_or_else(|| CommandError::user_not_found(&self.id))?;
+        if let Some(name) = &self.name {
+            user.name = name.clone();
+        }
+        if let Some(email) = &self.email {
+            user.email = email.clone();
+        }
+
+        self.user_repository.update(transaction, user).await?;
+        // created_at など集約が持たない項目も返すため、同じトランザクションで読み直す
+        *self.updated.lock().unwrap() = self.user_query.find_by_id(transaction, &self.id).await?;
+        Ok(())
+    }
+
+    fn operation_type(&self) -> &'static str {
+        "update_user"
+    }
+}
+
+pub struct UpdateUserUseCase {
+    repository: Arc<dyn UserCommand>,
+    user_query: Arc<dyn UserQuery>,
+    transaction_manager: Arc<dyn TransactionManager>,
+}
+
+impl UpdateUserUseCase {
+    pub fn new(
+        repository: Arc<dyn UserCommand>,
+        user_query: Arc<dyn UserQuery>,
+        transaction_manager: Arc<dyn TransactionManager>,
+    ) -> Self {
+        Self {
+            repository,
+            user_query,
+            transaction_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl UpdateUserInputBoundary for UpdateUserUseCase {
+    #[instrument(name = "update_user", skip_all, fields(tenant_id = %tenant_id.as_str(), user_id = %id))]
+    async fn execute(
+        &self,
+        tenant_id: TenantId,
+        id: String,
+        input: UpdateUserInput,
+        output_boundary: &mut dyn UpdateUserOutputBoundary,
+    ) -> Result<(), UpdateUserError> {
+        // 指定された項目をすべて検証し、違反をまとめて返す
+        let name = input.name.map(UserName::try_from).transpose();
+        let email = input.email.map(Email::try_from).transpose();
+        let (name, email) = match (name, email) {
+            (Ok(name), Ok(email)) => (name, email),
+            (name, email) => {
+                let violations: Vec<_> = [name.err(), email.err()].into_iter().flatten().collect();
+                return Err(UpdateUserValidationError::InvalidFields(violations).into());
+            }
+        };
+
+        let updated = Arc::new(Mutex::new(None));
+        let operation = Box::new(UpdateUserOperation {
+            id,
+            name,
+            email,
+            user_repository: self.repository.clone(),
+            user_query: self.user_query.clone(),
+            updated: updated.clone(),
+        });
+        let context = TransactionContext::new().with_tenant_id(tenant_id.as_str());
+        self.transaction_manager
+            .execute_with_context(context, operation)
+            .await?;
+
+        let user = updated.lock().unwrap().take().ok_or_else(|| {
+            UpdateUserOutputError::InvalidStateError("Updated user was not recorded".to_string())
+        })?;
+        output_boundary.execute(user)?;
+
+        Ok(())
+    }
+}
@@ -1,13 +1,39 @@
 use thiserror::Error;
 
+use crate::adapter::cli::error::CliError;
+use crate::adapter::init::AppInitializerError;
+
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error)]
 pub enum ApplicationError {
     #[error("Failed to initialize database: {0}")]
     DatabaseInitError(String),
     #[error("Failed to initialize application state: {0}")]
     InitializationError(String),
-    #[error("Failed to start server: {0}")]
+    #[error("Server error: {0}")]
     ServerError(String),
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
+    #[error("Command failed: {0}")]
+    CommandError(String),
+}
+
+impl From<AppInitializerError> for ApplicationError {
+    fn from(error: AppInitializerError) -> Self {
+        match error {
+            AppInitializerError::DatabaseInitError(message) => {
+                ApplicationError::DatabaseInitError(message)
+            }
+            other => ApplicationError::InitializationError(other.to_string()),
+        }
+    }
+}
+
+impl From<CliError> for ApplicationError {
+    fn from(error: CliError) -> Self {
+        match error {
+            CliError::InitError(error) => error.into(),
+            other => ApplicationError::CommandError(other.to_string()),
+        }
+    }
 }
@@ -2,38 +2,120 @@ mod adapter;
 pub mod core;
 mod error;
 
+use crate::adapter::cli::command::{Cli, Command};
 use crate::adapter::config::AppConfig;
 use crate::adapter::init::AppInitializer;
-use crate::adapter::web::app_state::AppState;
+use crate::adapter::shutdown::{wait_for_signal, Shutdown};
+use crate::adapter::telemetry::init_tracing;
 use crate::adapter::web::create_router::create_router;
 use crate::error::ApplicationError;
-use axum::routing::post;
-use axum::Router;
-use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
-use std::sync::Arc;
+use clap::Parser;
+use std::future::IntoFuture;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+// 締め切り後、ロールバックとレスポンスの送信を待つ時間
+const ABORT_GRACE_PERIOD: Duration = Duration::from_secs(5);
+// プールを閉じる際に、貸し出し中の接続が返るのを待つ時間
+const POOL_CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
 
 #[tokio::main]
 async fn main() {
     if let Err(e) = run().await {
-        println!("{}", e);
+        // ロガーの初期化前に失敗した場合は標準エラー出力に書く
+        if tracing::dispatcher::has_been_set() {
+            error!(error = %e, "Application terminated");
+        } else {
+            eprintln!("Application terminated: {}", e);
+        }
         std::process::exit(1)
     }
 }
 
 async fn run() -> Result<(), ApplicationError> {
-    let config = AppConfig::load();
+    let cli = Cli::parse();
+    let config = AppConfig::load(&cli.config)
+        .map_err(|e| ApplicationError::ConfigurationError(e.to_string()))?;
+    let command = cli.command.unwrap_or(Command::Serve);
+    let _telemetry = init_tracing(
+        config.log_level(),
+        config.otlp_endpoint(),
+        !matches!(command, Command::Serve),
+    )
+    .map_err(ApplicationError::ConfigurationError)?;
+
+    match command {
+        Command::Serve => serve(config).await,
+        Command::Migrate { command } => Ok(adapter::cli::migrate::run(&config, command).await?),
+        Command::Users { tenant, command } => {
+            Ok(adapter::cli::users::run(&config, tenant, command).await?)
+        }
+        Command::Check => Ok(adapter::cli::check::run(&config).await?),
+    }
+}
 
-    let state = AppInitializer::initialize(config)
+async fn serve(config: AppConfig) -> Result<(), ApplicationError> {
+    let bind_address = config.bind_address();
+    let shutdown_timeout = config.shutdown_timeout();
+    let shutdown = Shutdown::new();
+    let state = AppInitializer::initialize(config, &shutdown).await?;
+
+    let listener = tokio::net::TcpListener::bind(bind_address)
         .await
-        .map_err(|e| ApplicationError::ConfigurationError(e.to_string()))?;
+        .map_err(|e| {
+            ApplicationError::ServerError(format!("Failed to bind {}: {}", bind_address, e))
+        })?;
+    info!(%bind_address, "Server running");
 
-    let app = create_router(state);
+    let signal = shutdown.clone();
+    tokio::spawn(async move {
+        match wait_for_signal().await {
+            Ok(()) => info!("Shutdown signal received, draining in-flight requests"),
+            Err(e) => error!(error = %e, "Failed to listen for shutdown signal"),
+        }
+        signal.start_draining();
+    });
 
-    println!("Server running at http://localhost:3000");
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+    let server = axum::serve(listener, create_router(state.clone()))
+        .with_graceful_shutdown(shutdown.draining().cancelled_owned())
+        .into_future();
+    tokio::pin!(server);
 
-    axum::serve(listener, app).await.unwrap();
+    // 締め切りまでに終わらなかった作業単位はロールバックさせ、その完了を少しだけ待つ
+    let draining = shutdown.draining();
+    let result = tokio::select! {
+        result = &mut server => result.map_err(|e| ApplicationError::ServerError(e.to_string())),
+        _ = async {
+            draining.cancelled().await;
+            tokio::time::sleep(shutdown_timeout).await;
+        } => {
+            warn!(
+                timeout_secs = shutdown_timeout.as_secs(),
+                "Shutdown deadline exceeded, rolling back in-flight units of work"
+            );
+            shutdown.abort();
+            let _ = tokio::time::timeout(ABORT_GRACE_PERIOD, &mut server).await;
+            Err(ApplicationError::ServerError(format!(
+                "In-flight requests did not finish within {}s",
+                shutdown_timeout.as_secs()
+            )))
+        }
+    };
+
+    // 中断したタスクが接続を手放さなくても終了できるようにする
+    let close_pools = async {
+        state.pool.close().await;
+        for replica in &state.replicas {
+            replica.close().await;
+        }
+    };
+    match tokio::time::timeout(POOL_CLOSE_TIMEOUT, close_pools).await {
+        Ok(()) => info!("Database pools closed"),
+        Err(_) => warn!(
+            timeout_secs = POOL_CLOSE_TIMEOUT.as_secs(),
+            "Timed out closing database pools"
+        ),
+    }
 
-    Ok(())
+    result
 }